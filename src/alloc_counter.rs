@@ -0,0 +1,82 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicBool, AtomicIsize, AtomicUsize, Ordering};
+
+// Forwards to the system allocator and, while enabled, counts what passes through.
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+// Live bytes relative to `start`, negative if older memory was freed.
+static LIVE_BYTES: AtomicIsize = AtomicIsize::new(0);
+static PEAK_LIVE_BYTES: AtomicIsize = AtomicIsize::new(0);
+
+#[derive(Debug, Clone, Copy)]
+pub struct AllocStats {
+    pub allocations: usize,
+    pub bytes_allocated: usize,
+    pub peak_live_bytes: usize,
+}
+
+pub fn start() {
+    ALLOCATIONS.store(0, Ordering::SeqCst);
+    BYTES_ALLOCATED.store(0, Ordering::SeqCst);
+    LIVE_BYTES.store(0, Ordering::SeqCst);
+    PEAK_LIVE_BYTES.store(0, Ordering::SeqCst);
+    ENABLED.store(true, Ordering::SeqCst);
+}
+
+pub fn stop() -> AllocStats {
+    ENABLED.store(false, Ordering::SeqCst);
+    AllocStats {
+        allocations: ALLOCATIONS.load(Ordering::SeqCst),
+        bytes_allocated: BYTES_ALLOCATED.load(Ordering::SeqCst),
+        peak_live_bytes: PEAK_LIVE_BYTES.load(Ordering::SeqCst).max(0) as usize,
+    }
+}
+
+fn record(allocated: usize, freed: usize) {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return;
+    }
+
+    if allocated > 0 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES_ALLOCATED.fetch_add(allocated, Ordering::Relaxed);
+    }
+
+    let delta = allocated as isize - freed as isize;
+    let live = LIVE_BYTES.fetch_add(delta, Ordering::Relaxed) + delta;
+    PEAK_LIVE_BYTES.fetch_max(live, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let p = System.alloc(layout);
+        if !p.is_null() {
+            record(layout.size(), 0);
+        }
+        p
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let p = System.alloc_zeroed(layout);
+        if !p.is_null() {
+            record(layout.size(), 0);
+        }
+        p
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record(0, layout.size());
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let p = System.realloc(ptr, layout, new_size);
+        if !p.is_null() {
+            record(new_size, layout.size());
+        }
+        p
+    }
+}
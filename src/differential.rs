@@ -0,0 +1,166 @@
+use std::panic;
+
+use crate::rng::Rng;
+use crate::string_error::StringError;
+use crate::{Solver, YEARS};
+
+pub type Generator = fn(&mut Rng) -> String;
+
+pub struct Case {
+    pub day: usize,
+    pub solve: Solver,
+    pub reference: Solver,
+    pub generate: Generator,
+}
+
+struct Options {
+    year: Option<u32>,
+    day: Option<usize>,
+    runs: usize,
+    seed: u64,
+}
+
+impl Options {
+    fn from_args(args: &[String]) -> Result<Self, StringError> {
+        let mut result = Self {
+            year: None,
+            day: None,
+            runs: 200,
+            seed: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or(0),
+        };
+
+        let mut args = args.iter();
+        while let Some(a) = args.next() {
+            let value = args
+                .next()
+                .ok_or(format!("diff: missing value for {}", a))?;
+            let parse_err = |_| StringError::from(format!("diff: invalid value for {}", a));
+            match a.as_str() {
+                "--year" => result.year = Some(value.parse().map_err(parse_err)?),
+                "--day" => result.day = Some(value.parse().map_err(parse_err)?),
+                "--runs" => result.runs = value.parse().map_err(parse_err)?,
+                "--seed" => result.seed = value.parse().map_err(parse_err)?,
+                _ => return Err(format!("diff: unknown option {}", a).into()),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+pub fn run(args: &[String]) -> Result<(), StringError> {
+    let options = Options::from_args(args)?;
+    println!("Differential testing with seed {}.\n", options.seed);
+
+    // Panics of the solvers are reported as disagreements, not on stderr.
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut n_failed = 0;
+    let cases = YEARS
+        .iter()
+        .filter(|y| options.year.is_none() || options.year == Some(y.year))
+        .flat_map(|y| y.cases.iter().map(move |c| (y.year, c)))
+        .filter(|(_, c)| options.day.is_none() || options.day == Some(c.day));
+
+    for (year, case) in cases {
+        let mut rng = Rng::new(options.seed ^ ((year as u64) << 8) ^ case.day as u64);
+        let mut n_checked = 0;
+
+        for _ in 0..options.runs {
+            let input = (case.generate)(&mut rng);
+            let Some(found) = disagreement(case, &input) else {
+                n_checked += 1;
+                continue;
+            };
+
+            n_failed += 1;
+            let minimised = minimise(case, &input);
+            let (solver, reference) = disagreement(case, &minimised).unwrap_or(found);
+            println!(
+                "{} Day {:02}: disagreement after {} agreeing inputs. Minimised input:",
+                year, case.day, n_checked
+            );
+            println!("---\n{}---", minimised);
+            println!("Solver:\n{}", solver);
+            println!("Reference:\n{}", reference);
+            break;
+        }
+
+        if n_checked == options.runs {
+            println!("{} Day {:02}: {} inputs agree.", year, case.day, n_checked);
+        }
+    }
+
+    panic::set_hook(previous_hook);
+
+    if n_failed > 0 {
+        return Err(format!("diff: {} day(s) disagree with the reference.", n_failed).into());
+    }
+    Ok(())
+}
+
+// Returns the solver and reference output if they disagree on a valid input.
+// Inputs the reference rejects are not valid puzzle inputs and never disagree.
+fn disagreement(case: &Case, input: &str) -> Option<(String, String)> {
+    let expected = panic::catch_unwind(|| (case.reference)(input)).ok()?.ok()?;
+
+    let actual = match panic::catch_unwind(|| (case.solve)(input)) {
+        Ok(Ok(s)) => s,
+        Ok(Err(e)) => format!("error: {}\n", e),
+        Err(_) => "panicked\n".to_owned(),
+    };
+
+    if actual == expected {
+        None
+    } else {
+        Some((actual, expected))
+    }
+}
+
+// Shrinks the input first by whole lines, then by single chars, as long as
+// the solver still disagrees with the reference.
+fn minimise(case: &Case, input: &str) -> String {
+    let fails = |units: &[String]| disagreement(case, &units.concat()).is_some();
+
+    let lines: Vec<String> = input.split_inclusive('\n').map(|s| s.to_owned()).collect();
+    let lines = shrink(lines, fails);
+
+    let chars: Vec<String> = lines.concat().chars().map(|c| c.to_string()).collect();
+    shrink(chars, fails).concat()
+}
+
+fn shrink<F>(mut units: Vec<String>, fails: F) -> Vec<String>
+where
+    F: Fn(&[String]) -> bool,
+{
+    let mut chunk_size = units.len().div_ceil(2).max(1);
+
+    loop {
+        let mut removed_any = false;
+        let mut start = 0;
+
+        while start < units.len() {
+            let end = (start + chunk_size).min(units.len());
+            let mut candidate = units[..start].to_vec();
+            candidate.extend_from_slice(&units[end..]);
+
+            if fails(&candidate) {
+                units = candidate;
+                removed_any = true;
+            } else {
+                start = end;
+            }
+        }
+
+        if chunk_size == 1 && !removed_any {
+            return units;
+        }
+        if !removed_any {
+            chunk_size = chunk_size.div_ceil(2);
+        }
+    }
+}
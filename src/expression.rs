@@ -0,0 +1,94 @@
+// Binary integer arithmetic as it appears in the puzzle notes ("old * 19",
+// "pppw + sjmn"). What a variable stands for is up to the day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Value<V> {
+    Variable(V),
+    Literal(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation<V> {
+    Add(Value<V>, Value<V>),
+    Sub(Value<V>, Value<V>),
+    Mul(Value<V>, Value<V>),
+    Div(Value<V>, Value<V>),
+}
+
+impl<V> Value<V> {
+    pub fn resolve<F>(&self, lookup: F) -> i64
+    where
+        F: FnOnce(&V) -> i64,
+    {
+        match self {
+            Self::Variable(v) => lookup(v),
+            Self::Literal(c) => *c,
+        }
+    }
+}
+
+impl<V> Operation<V> {
+    // None for an unknown operator symbol.
+    pub fn from_symbol(symbol: &str, left: Value<V>, right: Value<V>) -> Option<Self> {
+        match symbol {
+            "+" => Some(Self::Add(left, right)),
+            "-" => Some(Self::Sub(left, right)),
+            "*" => Some(Self::Mul(left, right)),
+            "/" => Some(Self::Div(left, right)),
+            _ => None,
+        }
+    }
+
+    pub fn operands(&self) -> (&Value<V>, &Value<V>) {
+        match self {
+            Self::Add(l, r) | Self::Sub(l, r) | Self::Mul(l, r) | Self::Div(l, r) => (l, r),
+        }
+    }
+
+    // Applies the operation to already resolved operands. None on overflow or
+    // division by zero.
+    pub fn apply(&self, left: i64, right: i64) -> Option<i64> {
+        match self {
+            Self::Add(..) => left.checked_add(right),
+            Self::Sub(..) => left.checked_sub(right),
+            Self::Mul(..) => left.checked_mul(right),
+            Self::Div(..) => left.checked_div(right),
+        }
+    }
+
+    pub fn evaluate<F>(&self, lookup: F) -> Option<i64>
+    where
+        F: Fn(&V) -> i64,
+    {
+        let (l, r) = self.operands();
+        self.apply(l.resolve(&lookup), r.resolve(&lookup))
+    }
+
+    // The left operand that makes the operation result in `target`, given
+    // the right one. None if there is no integer solution.
+    pub fn solve_left(&self, target: i64, right: i64) -> Option<i64> {
+        match self {
+            Self::Add(..) => target.checked_sub(right),
+            Self::Sub(..) => target.checked_add(right),
+            Self::Mul(..) => exact_div(target, right),
+            Self::Div(..) => target.checked_mul(right),
+        }
+    }
+
+    // The right operand that makes the operation result in `target`, given
+    // the left one. None if there is no integer solution.
+    pub fn solve_right(&self, target: i64, left: i64) -> Option<i64> {
+        match self {
+            Self::Add(..) => target.checked_sub(left),
+            Self::Sub(..) => left.checked_sub(target),
+            Self::Mul(..) => exact_div(target, left),
+            Self::Div(..) => exact_div(left, target),
+        }
+    }
+}
+
+fn exact_div(a: i64, b: i64) -> Option<i64> {
+    if b == 0 || a % b != 0 {
+        return None;
+    }
+    a.checked_div(b)
+}
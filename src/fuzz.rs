@@ -0,0 +1,271 @@
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::differential::Case;
+use crate::rng::Rng;
+use crate::string_error::StringError;
+use crate::{input_path, YEARS};
+
+struct Options {
+    year: Option<u32>,
+    day: Option<usize>,
+    runs: usize,
+    seed: u64,
+    timeout: Duration,
+    corpus: PathBuf,
+}
+
+impl Options {
+    fn from_args(args: &[String]) -> Result<Self, StringError> {
+        let mut result = Self {
+            year: None,
+            day: None,
+            runs: 200,
+            seed: std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map(|d| d.as_nanos() as u64)
+                .unwrap_or(0),
+            timeout: Duration::from_millis(2000),
+            corpus: PathBuf::from("./fuzz_corpus"),
+        };
+
+        let mut args = args.iter();
+        while let Some(a) = args.next() {
+            let value = args
+                .next()
+                .ok_or(format!("fuzz: missing value for {}", a))?;
+            let parse_err = |_| StringError::from(format!("fuzz: invalid value for {}", a));
+            match a.as_str() {
+                "--year" => result.year = Some(value.parse().map_err(parse_err)?),
+                "--day" => result.day = Some(value.parse().map_err(parse_err)?),
+                "--runs" => result.runs = value.parse().map_err(parse_err)?,
+                "--seed" => result.seed = value.parse().map_err(parse_err)?,
+                "--timeout-ms" => {
+                    result.timeout = Duration::from_millis(value.parse().map_err(parse_err)?)
+                }
+                "--corpus" => result.corpus = PathBuf::from(value),
+                _ => return Err(format!("fuzz: unknown option {}", a).into()),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+enum Outcome {
+    Finished,
+    Panicked(String),
+    TimedOut,
+}
+
+pub fn run(args: &[String]) -> Result<(), StringError> {
+    let options = Options::from_args(args)?;
+    println!(
+        "Fuzzing with seed {}, saving findings to {}.\n",
+        options.seed,
+        options.corpus.display()
+    );
+
+    // Panic messages are collected from the worker threads instead.
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let mut n_findings = 0;
+    let cases = YEARS
+        .iter()
+        .filter(|y| options.year.is_none() || options.year == Some(y.year))
+        .flat_map(|y| y.cases.iter().map(move |c| (y.year, c)))
+        .filter(|(_, c)| options.day.is_none() || options.day == Some(c.day));
+
+    for (year, case) in cases {
+        let mut rng = Rng::new(options.seed ^ ((year as u64) << 8) ^ case.day as u64);
+        let real_input = std::fs::read_to_string(input_path(year, case.day)).ok();
+        let mut n_crashes = 0;
+        let mut timed_out = false;
+
+        for _ in 0..options.runs {
+            let valid = match &real_input {
+                Some(s) if rng.chance(1, 4) => s.clone(),
+                _ => (case.generate)(&mut rng),
+            };
+            let input = mutate(&valid, &mut rng);
+
+            let kind = match run_with_timeout(case, &input, options.timeout) {
+                Outcome::Finished => continue,
+                Outcome::Panicked(message) => {
+                    n_crashes += 1;
+                    println!("{} Day {:02}: panicked: {}", year, case.day, message);
+                    "crash"
+                }
+                Outcome::TimedOut => {
+                    timed_out = true;
+                    println!(
+                        "{} Day {:02}: no answer within {:?}.",
+                        year, case.day, options.timeout
+                    );
+                    "timeout"
+                }
+            };
+
+            n_findings += 1;
+            let path = save(&options.corpus, year, case.day, kind, &input)?;
+            println!("        saved input to {}", path.display());
+
+            // A timed out worker keeps spinning, so give up on this day.
+            if timed_out {
+                break;
+            }
+        }
+
+        if n_crashes == 0 && !timed_out {
+            println!(
+                "{} Day {:02}: {} inputs without crash.",
+                year, case.day, options.runs
+            );
+        }
+    }
+
+    panic::set_hook(previous_hook);
+
+    if n_findings > 0 {
+        return Err(format!("fuzz: {} crashing or hanging input(s).", n_findings).into());
+    }
+    Ok(())
+}
+
+fn run_with_timeout(case: &Case, input: &str, timeout: Duration) -> Outcome {
+    let (sender, receiver) = mpsc::channel();
+    let solve = case.solve;
+    let input = input.to_owned();
+
+    let worker = thread::spawn(move || {
+        // Parse errors are fine, only panics and hangs are findings.
+        let _ = solve(&input);
+        let _ = sender.send(());
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(()) => Outcome::Finished,
+        Err(mpsc::RecvTimeoutError::Timeout) => Outcome::TimedOut,
+        Err(mpsc::RecvTimeoutError::Disconnected) => {
+            let payload = worker.join().err();
+            let message = payload
+                .as_ref()
+                .and_then(|p| {
+                    p.downcast_ref::<&str>()
+                        .map(|s| s.to_string())
+                        .or_else(|| p.downcast_ref::<String>().cloned())
+                })
+                .unwrap_or_else(|| "unknown panic".to_owned());
+            Outcome::Panicked(message)
+        }
+    }
+}
+
+// Applies one to four random edits to a valid input.
+fn mutate(input: &str, rng: &mut Rng) -> String {
+    let mut lines: Vec<String> = input.lines().map(|l| l.to_owned()).collect();
+    if lines.is_empty() {
+        lines.push(String::new());
+    }
+
+    for _ in 0..rng.range(1, 5) {
+        let i = rng.index(lines.len());
+        match rng.range(0, 8) {
+            0 => {
+                if lines.len() > 1 {
+                    lines.remove(i);
+                }
+            }
+            1 => {
+                let l = lines[i].clone();
+                lines.insert(i, l);
+            }
+            2 => {
+                let j = rng.index(lines.len());
+                lines.swap(i, j);
+            }
+            3 => lines.truncate(i + 1),
+            4 => mutate_char(&mut lines[i], rng, |_| None),
+            5 => {
+                let c = *rng.pick(&[' ', '-', ',', '[', ']', '\r', '0', 'x', 'é']);
+                mutate_char(&mut lines[i], rng, |_| Some(c));
+            }
+            6 => lines.insert(i, String::new()),
+            _ => mutate_number(&mut lines[i], rng),
+        }
+    }
+
+    let mut result = lines.join("\n");
+    if rng.chance(1, 2) {
+        result.push('\n');
+    }
+    result
+}
+
+// Removes the char at a random position and inserts whatever `replacement` returns.
+fn mutate_char<F>(line: &mut String, rng: &mut Rng, replacement: F)
+where
+    F: Fn(char) -> Option<char>,
+{
+    let mut chars: Vec<char> = line.chars().collect();
+    if chars.is_empty() {
+        chars.push('0');
+    }
+    let i = rng.index(chars.len());
+    match replacement(chars[i]) {
+        Some(c) if rng.chance(1, 2) => chars.insert(i, c),
+        Some(c) => chars[i] = c,
+        None => {
+            chars.remove(i);
+        }
+    }
+    *line = chars.into_iter().collect();
+}
+
+// Replaces the first number of a line by an extreme value.
+fn mutate_number(line: &mut String, rng: &mut Rng) {
+    let Some(start) = line.find(|c: char| c.is_ascii_digit()) else {
+        return;
+    };
+    let end = line[start..]
+        .find(|c: char| !c.is_ascii_digit())
+        .map_or(line.len(), |e| start + e);
+    let value = rng
+        .pick(&[
+            "0",
+            "1",
+            "-1",
+            "999999999",
+            "99999999999999999999",
+            "-2000000",
+        ])
+        .to_string();
+    line.replace_range(start..end, &value);
+}
+
+fn save(
+    corpus: &Path,
+    year: u32,
+    day: usize,
+    kind: &str,
+    input: &str,
+) -> Result<PathBuf, StringError> {
+    std::fs::create_dir_all(corpus)
+        .map_err(|_| format!("fuzz: could not create {}", corpus.display()))?;
+
+    // Name files by content so that repeated findings do not pile up.
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for b in input.bytes() {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x0100_0000_01b3);
+    }
+
+    let path = corpus.join(format!("y{}_d{:02}_{}_{:016x}.txt", year, day, kind, hash));
+    std::fs::write(&path, input)
+        .map_err(|_| format!("fuzz: could not write {}", path.display()))?;
+    Ok(path)
+}
@@ -0,0 +1,66 @@
+// Rectangular grids stored row by row, shared by the days that search them.
+
+// The up to four orthogonal neighbors of (i, j) that lie within the grid.
+pub fn neighbors(i: usize, j: usize, height: usize, width: usize) -> NeighborIter {
+    NeighborIter {
+        i,
+        j,
+        width,
+        height,
+        dir: 0,
+    }
+}
+
+pub struct NeighborIter {
+    i: usize,
+    j: usize,
+    width: usize,
+    height: usize,
+    dir: u8,
+}
+
+impl Iterator for NeighborIter {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.dir {
+            0 => {
+                if self.j + 1 < self.width {
+                    self.dir += 1;
+                    Some((self.i, self.j + 1))
+                } else {
+                    self.dir += 1;
+                    self.next()
+                }
+            }
+            1 => {
+                if self.i > 0 {
+                    self.dir += 1;
+                    Some((self.i - 1, self.j))
+                } else {
+                    self.dir += 1;
+                    self.next()
+                }
+            }
+            2 => {
+                if self.j > 0 {
+                    self.dir += 1;
+                    Some((self.i, self.j - 1))
+                } else {
+                    self.dir += 1;
+                    self.next()
+                }
+            }
+            3 => {
+                if self.i + 1 < self.height {
+                    self.dir += 1;
+                    Some((self.i + 1, self.j))
+                } else {
+                    self.dir += 1;
+                    self.next()
+                }
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::Path;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::string_error::StringError;
+
+// One line of the history file: a single part of one day in one run.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub timestamp: u64,
+    pub run_id: String,
+    pub year: u32,
+    pub day: usize,
+    pub part: usize,
+    pub answer: String,
+    pub duration: Duration,
+    // How the run was built, see `build_tag`. Durations are only compared
+    // between runs with the same tag.
+    pub build: String,
+}
+
+impl Entry {
+    fn to_line(&self) -> String {
+        format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+            self.timestamp,
+            self.run_id,
+            self.year,
+            self.day,
+            self.part,
+            self.duration.as_nanos(),
+            self.build,
+            escape(&self.answer)
+        )
+    }
+
+    fn from_line(line: &str) -> Result<Self, StringError> {
+        let err = || StringError::from(format!("history: invalid line \"{}\"", line));
+        let columns: Vec<&str> = line.splitn(8, '\t').collect();
+        if columns.len() != 8 {
+            return Err(err());
+        }
+
+        Ok(Self {
+            timestamp: columns[0].parse().map_err(|_| err())?,
+            run_id: columns[1].to_owned(),
+            year: columns[2].parse().map_err(|_| err())?,
+            day: columns[3].parse().map_err(|_| err())?,
+            part: columns[4].parse().map_err(|_| err())?,
+            duration: Duration::from_nanos(columns[5].parse().map_err(|_| err())?),
+            build: columns[6].to_owned(),
+            answer: unescape(columns[7]),
+        })
+    }
+}
+
+// Debug builds and runs counting allocations are much slower than plain
+// release runs, so their durations are kept apart.
+pub fn build_tag(count_allocations: bool) -> String {
+    let profile = if cfg!(debug_assertions) {
+        "debug"
+    } else {
+        "release"
+    };
+    if count_allocations {
+        format!("{}+alloc", profile)
+    } else {
+        profile.to_owned()
+    }
+}
+
+// Identifies one run without relying on git: start time and process id.
+pub fn new_run_id() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or(0);
+    format!("{:x}-{:x}", nanos, std::process::id())
+}
+
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+// Splits solver output like "Day 01/02: 42" into (part, answer). Lines not
+// starting a new part (the drawing of d10) belong to the previous answer.
+pub fn split_answers(output: &str) -> Vec<(usize, String)> {
+    let mut result: Vec<(usize, String)> = Vec::new();
+
+    for line in output.lines() {
+        let part = line
+            .strip_prefix("Day ")
+            .and_then(|l| l.split_once(':'))
+            .and_then(|(id, _)| id.split_once('/'))
+            .and_then(|(_, part)| part.parse::<usize>().ok());
+
+        match (part, result.last_mut()) {
+            (Some(part), _) => {
+                let (_, answer) = line.split_once(':').unwrap();
+                result.push((part, answer.trim().to_owned()));
+            }
+            (None, Some((_, answer))) => {
+                if !answer.is_empty() {
+                    answer.push('\n');
+                }
+                *answer += line;
+            }
+            (None, None) => (),
+        }
+    }
+
+    result
+}
+
+pub fn load(path: &Path) -> Result<Vec<Entry>, StringError> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let content = std::fs::read_to_string(path)
+        .map_err(|_| format!("history: could not read {}", path.display()))?;
+    content
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(Entry::from_line)
+        .collect()
+}
+
+pub fn append(path: &Path, entries: &[Entry]) -> Result<(), StringError> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|_| format!("history: could not open {}", path.display()))?;
+
+    let content: String = entries.iter().map(|e| e.to_line()).collect();
+    file.write_all(content.as_bytes())
+        .map_err(|_| format!("history: could not write {}", path.display()).into())
+}
+
+// Compares the current run with earlier ones. Warns if an answer differs from
+// the most recent recorded one, or if a day took more than `slowdown_percent`
+// longer than the median of its recorded durations from runs built the same
+// way.
+pub fn check(history: &[Entry], current: &[Entry], slowdown_percent: f64) -> Vec<String> {
+    let mut warnings = Vec::new();
+
+    for e in current.iter() {
+        let previous = history
+            .iter()
+            .filter(|h| h.year == e.year && h.day == e.day && h.part == e.part)
+            .max_by_key(|h| h.timestamp);
+
+        if let Some(p) = previous {
+            if p.answer != e.answer {
+                warnings.push(format!(
+                    "{} Day {:02} part {}: answer changed from \"{}\" to \"{}\".",
+                    e.year,
+                    e.day,
+                    e.part,
+                    escape(&p.answer),
+                    escape(&e.answer)
+                ));
+            }
+        }
+    }
+
+    // Durations are per day, recorded once for each of its parts.
+    let mut days: Vec<(u32, usize, Duration, &str)> = current
+        .iter()
+        .map(|e| (e.year, e.day, e.duration, e.build.as_str()))
+        .collect();
+    days.dedup_by_key(|d| (d.0, d.1));
+
+    for (year, day, duration, build) in days {
+        let mut runs: Vec<(&str, Duration)> = history
+            .iter()
+            .filter(|h| h.year == year && h.day == day && h.build == build)
+            .map(|h| (h.run_id.as_str(), h.duration))
+            .collect();
+        runs.sort();
+        runs.dedup_by_key(|r| r.0);
+        if runs.is_empty() {
+            continue;
+        }
+
+        let mut durations: Vec<Duration> = runs.iter().map(|r| r.1).collect();
+        durations.sort();
+        let median = durations[durations.len() / 2];
+
+        let limit = median.as_secs_f64() * (1.0 + slowdown_percent / 100.0);
+        if duration.as_secs_f64() > limit {
+            warnings.push(format!(
+                "{} Day {:02}: took {:?}, more than {}% above the median of {:?}.",
+                year, day, duration, slowdown_percent, median
+            ));
+        }
+    }
+
+    warnings
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\")
+        .replace('\t', "\\t")
+        .replace('\n', "\\n")
+}
+
+fn unescape(s: &str) -> String {
+    let mut result = String::new();
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('t') => result.push('\t'),
+            Some('n') => result.push('\n'),
+            Some(d) => result.push(d),
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
@@ -1,68 +1,211 @@
-mod d01;
-mod d02;
-mod d03;
-mod d04;
-mod d05;
-mod d06;
-mod d07;
-mod d08;
-mod d09;
-mod d10;
-mod d11;
-mod d12;
-mod d13;
-mod d14;
-mod d15;
+mod alloc_counter;
+mod differential;
+mod expression;
+mod fuzz;
+mod grid;
+mod history;
+mod point;
+mod rng;
 mod string_error;
+mod tool;
+mod y2022;
 
-use std::time::Instant;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 use string_error::StringError;
 
+#[global_allocator]
+static ALLOCATOR: alloc_counter::CountingAllocator = alloc_counter::CountingAllocator;
+
+pub type Solver = fn(&str) -> Result<String, StringError>;
+
+pub struct Year {
+    pub year: u32,
+    // Solver of day n at index n - 1.
+    pub solvers: &'static [Solver],
+    pub cases: &'static [differential::Case],
+    pub tools: &'static [tool::DayTool],
+}
+
+pub const YEARS: [Year; 1] = [Year {
+    year: y2022::YEAR,
+    solvers: &y2022::SOLVERS,
+    cases: &y2022::CASES,
+    tools: &y2022::TOOLS,
+}];
+
+pub fn input_path(year: u32, day: usize) -> String {
+    format!("./inputs/{}/{:02}", year, day)
+}
+
 fn main() -> Result<(), StringError> {
-    let inputs = vec![
-        read_input_file("./inputs/input_01")?,
-        read_input_file("./inputs/input_02")?,
-        read_input_file("./inputs/input_03")?,
-        read_input_file("./inputs/input_04")?,
-        read_input_file("./inputs/input_05")?,
-        read_input_file("./inputs/input_06")?,
-        read_input_file("./inputs/input_07")?,
-        read_input_file("./inputs/input_08")?,
-        read_input_file("./inputs/input_09")?,
-        read_input_file("./inputs/input_10")?,
-        read_input_file("./inputs/input_11")?,
-        read_input_file("./inputs/input_12")?,
-        read_input_file("./inputs/input_13")?,
-        read_input_file("./inputs/input_14")?,
-        read_input_file("./inputs/input_15")?,
-    ];
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    match args.first().map(|a| a.as_str()) {
+        Some("diff") => differential::run(&args[1..]),
+        Some("fuzz") => fuzz::run(&args[1..]),
+        Some("tool") => tool::run(&args[1..]),
+        _ => run_all(&args),
+    }
+}
+
+struct RunOptions {
+    year: Option<u32>,
+    day: Option<usize>,
+    count_allocations: bool,
+    history: Option<PathBuf>,
+    slowdown_percent: f64,
+}
+
+impl RunOptions {
+    fn from_args(args: &[String]) -> Result<Self, StringError> {
+        let mut result = Self {
+            year: None,
+            day: None,
+            count_allocations: false,
+            history: Some(PathBuf::from("./history.tsv")),
+            slowdown_percent: 50.0,
+        };
+
+        let mut args = args.iter();
+        while let Some(a) = args.next() {
+            if a == "--alloc" {
+                result.count_allocations = true;
+                continue;
+            }
+            if a == "--no-history" {
+                result.history = None;
+                continue;
+            }
+
+            let value = args.next().ok_or(format!(
+                "Unknown command {}. Expected \"diff\", \"fuzz\", \"tool\" or run options.",
+                a
+            ))?;
+            let parse_err = |_| StringError::from(format!("Invalid value for {}", a));
+            let parse_float_err = |_| StringError::from(format!("Invalid value for {}", a));
+            match a.as_str() {
+                "--year" => result.year = Some(value.parse().map_err(parse_err)?),
+                "--day" => result.day = Some(value.parse().map_err(parse_err)?),
+                "--history" => result.history = Some(PathBuf::from(value)),
+                "--slowdown-percent" => {
+                    result.slowdown_percent = value.parse().map_err(parse_float_err)?
+                }
+                _ => return Err(format!("Unknown option {}", a).into()),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+fn run_all(args: &[String]) -> Result<(), StringError> {
+    let options = RunOptions::from_args(args)?;
+
+    let mut selected = Vec::new();
+    for y in YEARS
+        .iter()
+        .filter(|y| options.year.is_none() || options.year == Some(y.year))
+    {
+        for (i, solve) in y.solvers.iter().enumerate() {
+            let day = i + 1;
+            if options.day.is_some() && options.day != Some(day) {
+                continue;
+            }
+
+            // Inputs are personal, days without one are skipped.
+            let path = input_path(y.year, day);
+            if !Path::new(&path).exists() {
+                println!(
+                    "No input for {} Day {:02} at {}, skipping.",
+                    y.year, day, path
+                );
+                continue;
+            }
+
+            let input = read_input_file(&path)?;
+            selected.push((y.year, day, solve, input));
+        }
+    }
+
+    if selected.is_empty() {
+        return Err("No solver matches the selected year and day.".into());
+    }
 
     println!("Read all inputs from disk.\n");
 
     let mut s = String::new();
+    let mut report = String::new();
+    let mut dt = Duration::ZERO;
+    let mut previous_year = None;
+    let mut entries = Vec::new();
+    let run_id = history::new_run_id();
+    let timestamp = history::now();
+    let build = history::build_tag(options.count_allocations);
+
+    for (year, day, solve, input) in selected.iter() {
+        if previous_year != Some(*year) {
+            s += &format!("Year {}:\n", year);
+            previous_year = Some(*year);
+        }
+
+        if options.count_allocations {
+            alloc_counter::start();
+        }
+        let t = Instant::now();
+        let answer = solve(input);
+        let dt_day = t.elapsed();
+        dt += dt_day;
 
-    let t = Instant::now();
-    s += &d01::solve(&inputs[0])?;
-    s += &d02::solve(&inputs[1])?;
-    s += &d03::solve(&inputs[2])?;
-    s += &d04::solve(&inputs[3])?;
-    s += &d05::solve(&inputs[4])?;
-    s += &d06::solve(&inputs[5])?;
-    s += &d07::solve(&inputs[6])?;
-    s += &d08::solve(&inputs[7])?;
-    s += &d09::solve(&inputs[8])?;
-    s += &d10::solve(&inputs[9])?;
-    s += &d11::solve(&inputs[10])?;
-    s += &d12::solve(&inputs[11])?;
-    s += &d13::solve(&inputs[12])?;
-    s += &d14::solve(&inputs[13])?;
-    s += &d15::solve(&inputs[14])?;
-    let dt = t.elapsed();
+        if options.count_allocations {
+            let stats = alloc_counter::stop();
+            report += &format!(
+                "{} Day {:02}: {:>12?} {:>10} allocations {:>12} bytes allocated {:>12} bytes peak\n",
+                year,
+                day,
+                dt_day,
+                stats.allocations,
+                stats.bytes_allocated,
+                stats.peak_live_bytes
+            );
+        }
+
+        let answer = answer?;
+        for (part, a) in history::split_answers(&answer) {
+            entries.push(history::Entry {
+                timestamp,
+                run_id: run_id.clone(),
+                year: *year,
+                day: *day,
+                part,
+                answer: a,
+                duration: dt_day,
+                build: build.clone(),
+            });
+        }
+        s += &answer;
+    }
 
     println!("{}", s);
 
+    if options.count_allocations {
+        println!("{}", report);
+    }
+
     println!("Elapsed time for all solutions: {:?}", dt);
 
+    if let Some(path) = options.history {
+        let recorded = history::load(&path)?;
+        let warnings = history::check(&recorded, &entries, options.slowdown_percent);
+        if !warnings.is_empty() {
+            println!();
+        }
+        for w in warnings.iter() {
+            println!("Warning: {}", w);
+        }
+        history::append(&path, &entries)?;
+    }
+
     Ok(())
 }
 
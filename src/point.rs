@@ -0,0 +1,55 @@
+use std::ops::Add;
+
+// Lattice points shared by the grid puzzles. Which way y points is up to the day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    pub fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point3 {
+    pub fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+
+    // The six points sharing a face with this one.
+    pub fn neighbors(&self) -> [Self; 6] {
+        [
+            *self + Self::new(1, 0, 0),
+            *self + Self::new(-1, 0, 0),
+            *self + Self::new(0, 1, 0),
+            *self + Self::new(0, -1, 0),
+            *self + Self::new(0, 0, 1),
+            *self + Self::new(0, 0, -1),
+        ]
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::new(self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
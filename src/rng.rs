@@ -0,0 +1,43 @@
+// Small xorshift generator so that generated inputs are reproducible from a seed.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        // A zero state would only ever produce zeros.
+        Self {
+            state: seed ^ 0x9e37_79b9_7f4a_7c15,
+        }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        let mut x = self.state;
+        x ^= x << 13;
+        x ^= x >> 7;
+        x ^= x << 17;
+        self.state = x;
+        x.wrapping_mul(0x2545_f491_4f6c_dd1d)
+    }
+
+    // Uniform value in lower..upper (upper exclusive).
+    pub fn range(&mut self, lower: i64, upper: i64) -> i64 {
+        assert!(lower < upper);
+        let span = (upper - lower) as u64;
+        lower + (self.next_u64() % span) as i64
+    }
+
+    pub fn index(&mut self, len: usize) -> usize {
+        assert!(len > 0);
+        (self.next_u64() % len as u64) as usize
+    }
+
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.next_u64() % denominator < numerator
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.index(items.len())]
+    }
+}
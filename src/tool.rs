@@ -0,0 +1,79 @@
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use crate::string_error::StringError;
+use crate::{input_path, YEARS};
+
+// Day specific extras beyond the puzzle answers, run with
+// `cargo run -- tool --day 1 [--year 2022] [--input PATH] [day options]`.
+// The input is read from the day's input file, or from stdin for `--input -`.
+// All options the day tool does not share with others are passed on to it.
+pub type Tool = fn(&mut dyn BufRead, &[String]) -> Result<String, StringError>;
+
+pub struct DayTool {
+    pub day: usize,
+    pub run: Tool,
+}
+
+struct Options {
+    year: Option<u32>,
+    day: Option<usize>,
+    input: Option<String>,
+    tool_args: Vec<String>,
+}
+
+impl Options {
+    fn from_args(args: &[String]) -> Result<Self, StringError> {
+        let mut result = Self {
+            year: None,
+            day: None,
+            input: None,
+            tool_args: Vec::new(),
+        };
+
+        let mut args = args.iter();
+        while let Some(a) = args.next() {
+            if !matches!(a.as_str(), "--year" | "--day" | "--input") {
+                result.tool_args.push(a.clone());
+                continue;
+            }
+
+            let value = args
+                .next()
+                .ok_or(format!("tool: missing value for {}", a))?;
+            let parse_err = |_| StringError::from(format!("tool: invalid value for {}", a));
+            match a.as_str() {
+                "--year" => result.year = Some(value.parse().map_err(parse_err)?),
+                "--day" => result.day = Some(value.parse().map_err(parse_err)?),
+                _ => result.input = Some(value.clone()),
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+pub fn run(args: &[String]) -> Result<(), StringError> {
+    let options = Options::from_args(args)?;
+    let day = options.day.ok_or("tool: --day is required.")?;
+
+    // Without --year, the latest year with a tool for that day.
+    let (year, tool) = YEARS
+        .iter()
+        .rev()
+        .filter(|y| options.year.is_none() || options.year == Some(y.year))
+        .find_map(|y| y.tools.iter().find(|t| t.day == day).map(|t| (y.year, t)))
+        .ok_or(format!("tool: no tool for day {}.", day))?;
+
+    let output = match options.input.as_deref() {
+        Some("-") => (tool.run)(&mut std::io::stdin().lock(), &options.tool_args)?,
+        input => {
+            let path = input.map_or_else(|| input_path(year, day), |p| p.to_owned());
+            let file = File::open(&path).map_err(|_| format!("Could not read file {}", path))?;
+            (tool.run)(&mut BufReader::new(file), &options.tool_args)?
+        }
+    };
+
+    print!("{}", output);
+    Ok(())
+}
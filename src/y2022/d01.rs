@@ -0,0 +1,328 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
+
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    solve_stream(&mut input.as_bytes())
+}
+
+// Same as `solve`, but reads one line at a time, so arbitrarily large inputs
+// (generated logs, pipes) need only constant memory.
+pub fn solve_stream(input: &mut dyn BufRead) -> Result<String, StringError> {
+    let mut max_calories = TopN::new(3);
+    for e in ElfReader::new(input) {
+        max_calories.push(e?.calories);
+    }
+
+    let max_calories = max_calories.into_sorted_vec();
+    let mut result = format!(
+        "Day 01/01: {}\n",
+        max_calories.first().copied().unwrap_or(0)
+    );
+    result += &format!("Day 01/02: {}\n", max_calories.iter().sum::<u64>());
+    Ok(result)
+}
+
+// Options: `--top N` (default 3), `--median` to add the median to the
+// statistics and `--report` to list every elf. The input is streamed, so
+// memory stays constant apart from the top elves. Only the median and the
+// report need memory linear in the number of elves: one number per elf for
+// the median, every elf for the report.
+pub fn tool(input: &mut dyn BufRead, args: &[String]) -> Result<String, StringError> {
+    let mut n = 3;
+    let mut median = false;
+    let mut report = false;
+
+    let mut args = args.iter();
+    while let Some(a) = args.next() {
+        match a.as_str() {
+            "--top" => {
+                n = args
+                    .next()
+                    .and_then(|v| v.parse().ok())
+                    .ok_or("d01: --top needs a number.")?
+            }
+            "--median" => median = true,
+            "--report" => report = true,
+            _ => return Err(format!("d01: unknown option {}", a).into()),
+        }
+    }
+
+    // Ties go to the elf listed first.
+    let mut top = TopN::new(n);
+    let mut stats = Stats::new(median);
+    let mut elves = Vec::new();
+
+    for e in ElfReader::new(input) {
+        let e = e?;
+        top.push((e.calories, Reverse(e.index)));
+        stats.push(e.calories);
+        if report {
+            elves.push(e);
+        }
+    }
+    let top = top.into_sorted_vec();
+
+    let mut result = format!(
+        "Top {} elves: {}\n",
+        n,
+        top.iter()
+            .map(|(c, Reverse(i))| format!("#{} ({})", i, c))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    result += &format!(
+        "Calories of the top {}: {}\n",
+        n,
+        top.iter().map(|(c, _)| c).sum::<u64>()
+    );
+    result += &format!("Elves: {}\n", stats.count);
+    if let Some(mean) = stats.mean() {
+        result += &format!("Mean: {:.2}\n", mean);
+        if let Some(median) = stats.median() {
+            result += &format!("Median: {}\n", median);
+        }
+        result += &format!("Max: {}\n", stats.max);
+    }
+
+    if report {
+        result += &ranking_report(&elves);
+    }
+
+    Ok(result)
+}
+
+#[derive(Debug)]
+struct Elf {
+    // Position in the input, starting at 1.
+    index: usize,
+    n_items: usize,
+    calories: u64,
+}
+
+// Yields the elves of the input one after the other, reading only as many
+// lines as needed. Elves are separated by any number of blank lines, the last
+// one does not need to be followed by one. Lines may end in CRLF and numbers
+// may be padded with whitespace.
+struct ElfReader<'a> {
+    input: &'a mut dyn BufRead,
+    line: String,
+    n_elves: usize,
+    failed: bool,
+}
+
+impl<'a> ElfReader<'a> {
+    fn new(input: &'a mut dyn BufRead) -> Self {
+        Self {
+            input,
+            line: String::new(),
+            n_elves: 0,
+            failed: false,
+        }
+    }
+
+    fn read_elf(&mut self) -> Result<Option<Elf>, StringError> {
+        let mut current: Option<Elf> = None;
+
+        loop {
+            self.line.clear();
+            let n_read = self
+                .input
+                .read_line(&mut self.line)
+                .map_err(|_| "d01: could not read input.")?;
+            if n_read == 0 {
+                return Ok(current);
+            }
+
+            let line = self.line.trim();
+            if line.is_empty() {
+                if current.is_some() {
+                    return Ok(current);
+                }
+                continue;
+            }
+
+            let v: u64 = line
+                .parse::<u64>()
+                .map_err(|_| "Could not parse number in d01")?;
+
+            let elf = current.get_or_insert(Elf {
+                index: self.n_elves + 1,
+                n_items: 0,
+                calories: 0,
+            });
+            elf.n_items += 1;
+            elf.calories = elf
+                .calories
+                .checked_add(v)
+                .ok_or("d01: calories overflow.")?;
+        }
+    }
+}
+
+impl Iterator for ElfReader<'_> {
+    type Item = Result<Elf, StringError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+
+        match self.read_elf() {
+            Ok(Some(elf)) => {
+                self.n_elves += 1;
+                Some(Ok(elf))
+            }
+            Ok(None) => None,
+            Err(e) => {
+                self.failed = true;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+// Running statistics of the elves' totals.
+#[derive(Debug)]
+struct Stats {
+    count: usize,
+    sum: u128,
+    max: u64,
+    // Every total, only kept if the median is wanted.
+    totals: Option<Vec<u64>>,
+}
+
+impl Stats {
+    fn new(keep_totals: bool) -> Self {
+        Self {
+            count: 0,
+            sum: 0,
+            max: 0,
+            totals: keep_totals.then(Vec::new),
+        }
+    }
+
+    fn push(&mut self, calories: u64) {
+        self.count += 1;
+        self.sum += calories as u128;
+        self.max = self.max.max(calories);
+        if let Some(totals) = self.totals.as_mut() {
+            totals.push(calories);
+        }
+    }
+
+    fn mean(&self) -> Option<f64> {
+        if self.count == 0 {
+            return None;
+        }
+        Some(self.sum as f64 / self.count as f64)
+    }
+
+    // Selects the middle element(s) in linear time instead of sorting. None
+    // if the totals were not kept.
+    fn median(&mut self) -> Option<f64> {
+        let totals = self.totals.as_mut()?;
+        let n = totals.len();
+        if n == 0 {
+            return None;
+        }
+
+        let (lower, upper, _) = totals.select_nth_unstable(n / 2);
+        let upper = *upper as f64;
+        if n % 2 == 1 {
+            return Some(upper);
+        }
+
+        let lower = *lower.iter().max().unwrap() as f64;
+        Some((lower + upper) / 2.0)
+    }
+}
+
+// Elves with equal totals share a rank and are listed in input order.
+fn ranking_report(elves: &[Elf]) -> String {
+    let mut sorted: Vec<&Elf> = elves.iter().collect();
+    sorted.sort_by_key(|e| (Reverse(e.calories), e.index));
+
+    let mut result = format!(
+        "{:>6} {:>6} {:>6} {:>10}\n",
+        "Rank", "Elf", "Items", "Calories"
+    );
+    let mut rank = 0;
+    for (i, e) in sorted.iter().enumerate() {
+        if i == 0 || sorted[i - 1].calories != e.calories {
+            rank = i + 1;
+        }
+        result += &format!(
+            "{:>6} {:>6} {:>6} {:>10}\n",
+            rank, e.index, e.n_items, e.calories
+        );
+    }
+    result
+}
+
+// The `n` largest values pushed so far. A min-heap of them, so the smallest
+// one is the first to go when a larger value arrives.
+struct TopN<T: Ord> {
+    n: usize,
+    heap: BinaryHeap<Reverse<T>>,
+}
+
+impl<T: Ord> TopN<T> {
+    fn new(n: usize) -> Self {
+        Self {
+            n,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    fn push(&mut self, value: T) {
+        self.heap.push(Reverse(value));
+        if self.heap.len() > self.n {
+            self.heap.pop();
+        }
+    }
+
+    // Largest first.
+    fn into_sorted_vec(self) -> Vec<T> {
+        self.heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|r| r.0)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXPECTED: &str = "Day 01/01: 24000\nDay 01/02: 45000\n";
+
+    #[test]
+    fn final_elf_without_trailing_blank_line() {
+        let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000";
+        assert_eq!(solve(input).unwrap(), EXPECTED);
+    }
+
+    #[test]
+    fn several_blank_lines_between_elves() {
+        let input = "1000\n2000\n3000\n\n\n4000\n\n5000\n6000\n\n\n\n7000\n8000\n9000\n\n10000\n\n";
+        assert_eq!(solve(input).unwrap(), EXPECTED);
+    }
+
+    #[test]
+    fn crlf_line_endings() {
+        let input =
+            "1000\r\n2000\r\n3000\r\n\r\n4000\r\n\r\n5000\r\n6000\r\n\r\n7000\r\n8000\r\n9000\r\n\r\n10000\r\n";
+        assert_eq!(solve(input).unwrap(), EXPECTED);
+    }
+
+    #[test]
+    fn padded_numbers() {
+        let input =
+            " 1000\n2000 \n\t3000\n  \n4000\n\n  5000\t\n6000\n\n7000\n 8000 \n9000\n\n10000\n";
+        assert_eq!(solve(input).unwrap(), EXPECTED);
+    }
+}
@@ -0,0 +1,518 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::BufRead;
+
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let config = Config::rock_paper_scissors();
+
+    let points_part_one = config.total_points(input, Response::Move)?;
+    let points_part_two = config.total_points(input, Response::Outcome)?;
+
+    let mut result = format!("Day 02/01: {}\n", points_part_one);
+    result += &format!("Day 02/02: {}\n", points_part_two);
+    Ok(result)
+}
+
+// Options: `--game rps|rpsls` (default rps) or `--rules PATH` to score the
+// guide in another game, and `--analyse` for the best response mappings and
+// round statistics. See `Config::from_rules` for the rules file format.
+pub fn tool(input: &mut dyn BufRead, args: &[String]) -> Result<String, StringError> {
+    let mut config = Config::rock_paper_scissors();
+    let mut analyse = false;
+
+    let mut args = args.iter();
+    while let Some(a) = args.next() {
+        match a.as_str() {
+            "--game" => {
+                config = match args.next().map(|v| v.as_str()) {
+                    Some("rps") => Config::rock_paper_scissors(),
+                    Some("rpsls") => Config::rock_paper_scissors_lizard_spock(),
+                    _ => return Err("d02: --game needs rps or rpsls.".into()),
+                }
+            }
+            "--rules" => {
+                let path = args.next().ok_or("d02: --rules needs a path.")?;
+                let rules = std::fs::read_to_string(path)
+                    .map_err(|_| format!("Could not read file {}", path))?;
+                config = Config::from_rules(&rules)?;
+            }
+            "--analyse" => analyse = true,
+            _ => return Err(format!("d02: unknown option {}", a).into()),
+        }
+    }
+
+    let mut content = String::new();
+    input
+        .read_to_string(&mut content)
+        .map_err(|_| "d02: could not read input.")?;
+
+    let mut result = format!("Moves: {}\n", config.game.names.join(", "));
+    // A guide may only make sense under one of the interpretations.
+    for (name, response) in [("moves", Response::Move), ("outcomes", Response::Outcome)] {
+        match config.total_points(&content, response) {
+            Ok(p) => result += &format!("Points with {} as response: {}\n", name, p),
+            Err(e) => result += &format!("Points with {} as response: {}\n", name, e),
+        }
+    }
+
+    if analyse {
+        result += &config.analysis(&content)?;
+    }
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+const OUTCOMES: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+// How the second column of the strategy guide is read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Response {
+    Move,
+    Outcome,
+}
+
+// A hand game with an odd number of moves arranged in a cycle, so that every
+// move beats the (n - 1) / 2 moves before it and loses against the others.
+struct Game {
+    names: Vec<String>,
+    base_points: Vec<u64>,
+    // beats[a][b]: move a beats move b.
+    beats: Vec<Vec<bool>>,
+    // Points for a loss, a draw and a win.
+    outcome_points: [u64; 3],
+}
+
+impl Game {
+    fn cyclic(
+        names: Vec<String>,
+        base_points: Vec<u64>,
+        outcome_points: [u64; 3],
+    ) -> Result<Self, StringError> {
+        let n = names.len();
+        if n < 3 || n.is_multiple_of(2) {
+            return Err("d02: a cyclic game needs an odd number of at least 3 moves.".into());
+        }
+        if base_points.len() != n {
+            return Err("d02: every move needs its base points.".into());
+        }
+
+        let beats = (0..n)
+            .map(|a| {
+                (0..n)
+                    .map(|b| {
+                        let d = (a + n - b) % n;
+                        d >= 1 && d <= (n - 1) / 2
+                    })
+                    .collect()
+            })
+            .collect();
+
+        Ok(Self {
+            names,
+            base_points,
+            beats,
+            outcome_points,
+        })
+    }
+
+    fn n_moves(&self) -> usize {
+        self.names.len()
+    }
+
+    fn outcome(&self, own: usize, other: usize) -> Outcome {
+        if own == other {
+            Outcome::Draw
+        } else if self.beats[own][other] {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    fn points(&self, own: usize, other: usize) -> u64 {
+        self.base_points[own] + self.outcome_points[self.outcome(own, other) as usize]
+    }
+
+    // The move reaching `outcome` against `other`. With more than three moves
+    // several do, then the one with the most base points is played.
+    fn move_for(&self, other: usize, outcome: Outcome) -> usize {
+        (0..self.n_moves())
+            .filter(|m| self.outcome(*m, other) == outcome)
+            .max_by_key(|m| (self.base_points[*m], std::cmp::Reverse(*m)))
+            .unwrap()
+    }
+}
+
+// The puzzle's game and symbols.
+const ROCK_PAPER_SCISSORS: &str = "
+move Rock A X 1
+move Paper B Y 2
+move Scissors C Z 3
+outcome loss X 0
+outcome draw Y 3
+outcome win Z 6
+";
+
+// Lizard and Spock extend the symbols of the puzzle with D / V and E / W.
+const ROCK_PAPER_SCISSORS_LIZARD_SPOCK: &str = "
+move Rock A X 1
+move Spock E W 5
+move Paper B Y 2
+move Lizard D V 4
+move Scissors C Z 3
+outcome loss X 0
+outcome draw Y 3
+outcome win Z 6
+";
+
+// A game together with the symbols used in the strategy guide.
+struct Config {
+    game: Game,
+    // Symbols of the moves, by move index.
+    opponent_symbols: Vec<String>,
+    own_symbols: Vec<String>,
+    // Symbols of a loss, a draw and a win.
+    outcome_symbols: [String; 3],
+}
+
+impl Config {
+    fn rock_paper_scissors() -> Self {
+        Self::from_rules(ROCK_PAPER_SCISSORS).unwrap()
+    }
+
+    fn rock_paper_scissors_lizard_spock() -> Self {
+        Self::from_rules(ROCK_PAPER_SCISSORS_LIZARD_SPOCK).unwrap()
+    }
+
+    // One rule per line, blank lines and lines starting with '#' are ignored:
+    //
+    //   move <name> <opponent symbol> <own symbol> <points>
+    //   outcome <loss|draw|win> <own symbol> <points>
+    //
+    // Moves are listed in cycle order, each beating the (n - 1) / 2 moves
+    // listed before it (wrapping around). Every outcome needs exactly one rule.
+    fn from_rules(rules: &str) -> Result<Self, StringError> {
+        let mut names = Vec::new();
+        let mut base_points = Vec::new();
+        let mut opponent_symbols: Vec<String> = Vec::new();
+        let mut own_symbols: Vec<String> = Vec::new();
+        let mut outcomes: [Option<(String, u64)>; 3] = [None, None, None];
+
+        let parse_points = |s: &str, n: usize| -> Result<u64, StringError> {
+            s.parse::<u64>()
+                .map_err(|_| format!("d02: rules line {}: could not parse points.", n).into())
+        };
+
+        for (i, line) in rules.lines().map(|l| l.trim()).enumerate() {
+            let n = i + 1;
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            match fields[..] {
+                ["move", name, opponent, own, points] => {
+                    if opponent_symbols.iter().any(|s| s == opponent)
+                        || own_symbols.iter().any(|s| s == own)
+                    {
+                        return Err(format!("d02: rules line {}: symbol used twice.", n).into());
+                    }
+                    names.push(name.to_owned());
+                    opponent_symbols.push(opponent.to_owned());
+                    own_symbols.push(own.to_owned());
+                    base_points.push(parse_points(points, n)?);
+                }
+                ["outcome", outcome, symbol, points] => {
+                    let o = match outcome {
+                        "loss" => Outcome::Loss,
+                        "draw" => Outcome::Draw,
+                        "win" => Outcome::Win,
+                        _ => return Err(format!("d02: rules line {}: unknown outcome.", n).into()),
+                    };
+                    if outcomes[o as usize].is_some() {
+                        return Err(format!("d02: rules line {}: outcome defined twice.", n).into());
+                    }
+                    outcomes[o as usize] = Some((symbol.to_owned(), parse_points(points, n)?));
+                }
+                _ => {
+                    return Err(
+                        format!("d02: rules line {}: expected a move or outcome.", n).into(),
+                    )
+                }
+            }
+        }
+
+        let [Some(loss), Some(draw), Some(win)] = outcomes else {
+            return Err("d02: rules need a loss, a draw and a win outcome.".into());
+        };
+        if loss.0 == draw.0 || loss.0 == win.0 || draw.0 == win.0 {
+            return Err("d02: rules use an outcome symbol twice.".into());
+        }
+
+        Ok(Self {
+            game: Game::cyclic(names, base_points, [loss.1, draw.1, win.1])?,
+            opponent_symbols,
+            own_symbols,
+            outcome_symbols: [loss.0, draw.0, win.0],
+        })
+    }
+
+    fn round_points(
+        &self,
+        first: &str,
+        second: &str,
+        response: Response,
+    ) -> Result<u64, StringError> {
+        let other_move = self
+            .opponent_symbols
+            .iter()
+            .position(|s| *s == first)
+            .ok_or("d02: invalid input char")?;
+
+        let own_move = match response {
+            Response::Move => self.move_index(second).ok_or("d02: invalid input char")?,
+            Response::Outcome => {
+                let i = self
+                    .outcome_index(second)
+                    .ok_or("d02: invalid input char for intended result")?;
+                self.game.move_for(other_move, OUTCOMES[i])
+            }
+        };
+
+        Ok(self.game.points(own_move, other_move))
+    }
+
+    fn move_index(&self, symbol: &str) -> Option<usize> {
+        self.own_symbols.iter().position(|s| *s == symbol)
+    }
+
+    fn outcome_index(&self, symbol: &str) -> Option<usize> {
+        self.outcome_symbols.iter().position(|s| *s == symbol)
+    }
+
+    fn total_points(&self, input: &str, response: Response) -> Result<u64, StringError> {
+        let mut points = 0;
+
+        for line in input.lines() {
+            let trimmed = line.trim();
+            if trimmed.chars().count() == 0 {
+                continue;
+            }
+
+            let mut s = trimmed.split(' ');
+            let first = s.next().ok_or("d02: could not find other player move")?;
+            let second = s.next().ok_or("d02: could not find self player move")?;
+
+            points += self.round_points(first, second, response)?;
+        }
+
+        Ok(points)
+    }
+
+    // Rounds of the guide by opponent move and second symbol.
+    fn count_rounds<'a>(
+        &self,
+        input: &'a str,
+    ) -> Result<BTreeMap<(usize, &'a str), u64>, StringError> {
+        let mut result = BTreeMap::new();
+
+        for line in input.lines() {
+            let trimmed = line.trim();
+            if trimmed.chars().count() == 0 {
+                continue;
+            }
+
+            let mut s = trimmed.split(' ');
+            let first = s.next().ok_or("d02: could not find other player move")?;
+            let second = s.next().ok_or("d02: could not find self player move")?;
+
+            let other_move = self
+                .opponent_symbols
+                .iter()
+                .position(|s| *s == first)
+                .ok_or("d02: invalid input char")?;
+            *result.entry((other_move, second)).or_insert(0) += 1;
+        }
+
+        Ok(result)
+    }
+
+    // Total points when the second symbols are read through `mapping`: symbol
+    // i of the response becomes move mapping[i], or outcome mapping[i]. None
+    // if a symbol has no meaning in that interpretation.
+    fn mapped_points(
+        &self,
+        rounds: &BTreeMap<(usize, &str), u64>,
+        response: Response,
+        mapping: &[usize],
+    ) -> Option<u64> {
+        let mut points = 0;
+        for ((other, second), count) in rounds.iter() {
+            let own = match response {
+                Response::Move => mapping[self.move_index(second)?],
+                Response::Outcome => {
+                    let outcome = OUTCOMES[mapping[self.outcome_index(second)?]];
+                    self.game.move_for(*other, outcome)
+                }
+            };
+            points += count * self.game.points(own, *other);
+        }
+        Some(points)
+    }
+
+    fn analysis(&self, input: &str) -> Result<String, StringError> {
+        let rounds = self.count_rounds(input)?;
+        let n_rounds: u64 = rounds.values().sum();
+        if n_rounds == 0 {
+            return Err("d02: no rounds to analyse.".into());
+        }
+        let share = |count: u64| 100.0 * count as f64 / n_rounds as f64;
+
+        let mut result = format!("\nRounds: {}\n", n_rounds);
+        result += "Opponent moves:\n";
+        for (m, name) in self.game.names.iter().enumerate() {
+            let count: u64 = rounds
+                .iter()
+                .filter(|((other, _), _)| *other == m)
+                .map(|(_, c)| c)
+                .sum();
+            result += &format!("  {:<10} {:>6} {:>6.1}%\n", name, count, share(count));
+        }
+
+        let interpretations = [
+            ("moves", Response::Move, self.game.n_moves()),
+            ("outcomes", Response::Outcome, OUTCOMES.len()),
+        ];
+        for (name, response, n) in interpretations {
+            let identity: Vec<usize> = (0..n).collect();
+            let Some(guide) = self.mapped_points(&rounds, response, &identity) else {
+                result += &format!("With {} as response: not applicable\n", name);
+                continue;
+            };
+
+            // Only the symbols used in the guide make a difference, the
+            // others keep the remaining meanings in order.
+            let used: BTreeSet<usize> = rounds
+                .keys()
+                .filter_map(|(_, second)| match response {
+                    Response::Move => self.move_index(second),
+                    Response::Outcome => self.outcome_index(second),
+                })
+                .collect();
+            let used: Vec<usize> = used.into_iter().collect();
+            let n_mappings =
+                (n + 1 - used.len()..=n).try_fold(1u64, |a, v| a.checked_mul(v as u64));
+            if n_mappings.is_none_or(|m| m > MAX_MAPPINGS) {
+                result += &format!(
+                    "With {} as response: more than {} mappings, not searched\n",
+                    name, MAX_MAPPINGS
+                );
+                continue;
+            }
+
+            // Ties go to the mapping found first, the guide's own one.
+            let mut best = (identity, guide);
+            for_each_arrangement(n, used.len(), &mut |arrangement| {
+                let mut mapping = vec![0; n];
+                let mut unused = (0..n).filter(|v| !arrangement.contains(v));
+                for (i, m) in mapping.iter_mut().enumerate() {
+                    *m = match used.iter().position(|&u| u == i) {
+                        Some(j) => arrangement[j],
+                        None => unused.next().unwrap(),
+                    };
+                }
+
+                let points = self.mapped_points(&rounds, response, &mapping).unwrap();
+                if points > best.1 {
+                    best = (mapping, points);
+                }
+            });
+
+            let symbols = match response {
+                Response::Move => &self.own_symbols[..],
+                Response::Outcome => &self.outcome_symbols[..],
+            };
+            let mapping = best
+                .0
+                .iter()
+                .zip(symbols.iter())
+                .map(|(m, s)| match response {
+                    Response::Move => format!("{}={}", s, self.game.names[*m]),
+                    Response::Outcome => format!("{}={:?}", s, OUTCOMES[*m]),
+                })
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            result += &format!(
+                "With {} as response: guide {} ({:.3} per round), best {} ({:.3} per round) with {}\n",
+                name,
+                guide,
+                guide as f64 / n_rounds as f64,
+                best.1,
+                best.1 as f64 / n_rounds as f64,
+                mapping
+            );
+        }
+
+        result += &format!(
+            "{:<8} {:>6} {:>7} {:>6} {:>9}\n",
+            "Pair", "Rounds", "Share", "Moves", "Outcomes"
+        );
+        for ((other, second), count) in rounds.iter() {
+            let round = |response| {
+                self.round_points(&self.opponent_symbols[*other], second, response)
+                    .map_or("-".to_owned(), |p| p.to_string())
+            };
+            result += &format!(
+                "{:<8} {:>6} {:>6.1}% {:>6} {:>9}\n",
+                format!("{} {}", self.opponent_symbols[*other], second),
+                count,
+                share(*count),
+                round(Response::Move),
+                round(Response::Outcome)
+            );
+        }
+
+        Ok(result)
+    }
+}
+
+// Mappings tried at most by the analysis, so games with many moves do not
+// run for ages.
+const MAX_MAPPINGS: u64 = 1_000_000;
+
+// Calls `f` with every ordering of k distinct values out of 0..n. They are
+// produced one at a time, only the current one is kept.
+fn for_each_arrangement(n: usize, k: usize, f: &mut dyn FnMut(&[usize])) {
+    fn extend(
+        n: usize,
+        k: usize,
+        current: &mut Vec<usize>,
+        taken: &mut [bool],
+        f: &mut dyn FnMut(&[usize]),
+    ) {
+        if current.len() == k {
+            f(current);
+            return;
+        }
+        for v in 0..n {
+            if taken[v] {
+                continue;
+            }
+            taken[v] = true;
+            current.push(v);
+            extend(n, k, current, taken, f);
+            current.pop();
+            taken[v] = false;
+        }
+    }
+
+    extend(n, k, &mut Vec::with_capacity(k), &mut vec![false; n], f);
+}
@@ -0,0 +1,213 @@
+use std::io::BufRead;
+
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let (sum_priority, sum_badge_priority) = priority_sums(input, &Layout::default(), None)?;
+
+    let mut result = format!("Day 03/01: {}\n", sum_priority);
+    result += &format!("Day 03/02: {}\n", sum_badge_priority);
+    Ok(result)
+}
+
+// Options: `--compartments N` (default 2), `--group-size N` (default 3) and
+// `--verbose` to list the shared items of every rucksack and the badge of
+// every group, flagging the ones that do not have exactly one.
+pub fn tool(input: &mut dyn BufRead, args: &[String]) -> Result<String, StringError> {
+    let mut layout = Layout::default();
+    let mut verbose = false;
+
+    let mut args = args.iter();
+    while let Some(a) = args.next() {
+        if a == "--verbose" {
+            verbose = true;
+            continue;
+        }
+        let value = args
+            .next()
+            .and_then(|v| v.parse().ok())
+            .ok_or(format!("d03: {} needs a number.", a))?;
+        match a.as_str() {
+            "--compartments" => layout.n_compartments = value,
+            "--group-size" => layout.group_size = value,
+            _ => return Err(format!("d03: unknown option {}", a).into()),
+        }
+    }
+
+    let mut content = String::new();
+    input
+        .read_to_string(&mut content)
+        .map_err(|_| "d03: could not read input.")?;
+
+    let mut report = String::new();
+    let (sum_priority, sum_badge_priority) =
+        priority_sums(&content, &layout, verbose.then_some(&mut report))?;
+    let mut result = report;
+    result += &format!(
+        "Priorities of items in all {} compartments: {}\n",
+        layout.n_compartments, sum_priority
+    );
+    result += &format!(
+        "Priorities of badges of groups of {}: {}\n",
+        layout.group_size, sum_badge_priority
+    );
+    Ok(result)
+}
+
+// How the rucksacks are organised. The puzzle has two compartments per
+// rucksack and groups of three elves.
+struct Layout {
+    n_compartments: usize,
+    group_size: usize,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            n_compartments: 2,
+            group_size: 3,
+        }
+    }
+}
+
+// A set of item types, bit p - 1 standing for the item of priority p.
+type Items = u64;
+
+const NO_ITEMS: Items = 0;
+const ALL_ITEMS: Items = (1 << 52) - 1;
+
+struct Rucksack {
+    compartments: Vec<Items>,
+}
+
+impl Rucksack {
+    fn from_line(line: &str, n_compartments: usize) -> Result<Self, StringError> {
+        let total_item_count = line.chars().count();
+        if !total_item_count.is_multiple_of(n_compartments) {
+            return Err(
+                "d03: at least one rucksack cannot be split evenly into its compartments.".into(),
+            );
+        }
+
+        let mut compartments = vec![NO_ITEMS; n_compartments];
+        for (i, c) in line.chars().enumerate() {
+            let compartment = n_compartments * i / total_item_count;
+            compartments[compartment] |= item(c)?;
+        }
+
+        Ok(Self { compartments })
+    }
+
+    // Items found in every compartment.
+    fn shared(&self) -> Items {
+        self.compartments.iter().fold(ALL_ITEMS, |a, c| a & c)
+    }
+
+    fn items(&self) -> Items {
+        self.compartments.iter().fold(NO_ITEMS, |a, c| a | c)
+    }
+}
+
+fn item(c: char) -> Result<Items, StringError> {
+    let priority = match c {
+        'a'..='z' => (c as u64) - ('a' as u64) + 1,
+        'A'..='Z' => (c as u64) - ('A' as u64) + 27,
+        _ => return Err("d03: illegal item.".into()),
+    };
+    Ok(1 << (priority - 1))
+}
+
+fn item_names(items: Items) -> String {
+    ('a'..='z')
+        .chain('A'..='Z')
+        .enumerate()
+        .filter(|(i, _)| items & (1 << i) != 0)
+        .map(|(_, c)| c)
+        .collect()
+}
+
+// Empty unless there is not exactly one item.
+fn anomaly(items: Items, what: &str) -> String {
+    match items.count_ones() {
+        0 => format!("  <- no {}", what),
+        1 => String::new(),
+        n => format!("  <- {} {}s", n, what),
+    }
+}
+
+fn priority_sum(items: Items) -> u64 {
+    (0..52)
+        .filter(|i| items & (1 << i) != 0)
+        .map(|i| i + 1)
+        .sum()
+}
+
+// Blank lines are skipped and do not count towards groups. With `report`, one
+// line per rucksack and per group is appended to it.
+fn priority_sums(
+    input: &str,
+    layout: &Layout,
+    mut report: Option<&mut String>,
+) -> Result<(u64, u64), StringError> {
+    if layout.n_compartments == 0 || layout.group_size == 0 {
+        return Err("d03: need at least one compartment and one elf per group.".into());
+    }
+
+    let mut sum_priority = 0;
+    let mut sum_badge_priority = 0;
+    let mut badge_candidates = ALL_ITEMS;
+    let mut n_rucksacks = 0;
+    let mut group_first_line = 0;
+
+    for (i, line) in input.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let rucksack = Rucksack::from_line(line, layout.n_compartments)?;
+        let shared = rucksack.shared();
+        sum_priority += priority_sum(shared);
+        badge_candidates &= rucksack.items();
+
+        if let Some(report) = report.as_deref_mut() {
+            *report += &format!(
+                "Line {}: shared {:?}{}\n",
+                i + 1,
+                item_names(shared),
+                anomaly(shared, "shared item")
+            );
+        }
+
+        if n_rucksacks % layout.group_size == 0 {
+            group_first_line = i + 1;
+        }
+        n_rucksacks += 1;
+
+        if n_rucksacks % layout.group_size == 0 {
+            if let Some(report) = report.as_deref_mut() {
+                *report += &format!(
+                    "Group {} (lines {}-{}): badge {:?}{}\n",
+                    n_rucksacks / layout.group_size,
+                    group_first_line,
+                    i + 1,
+                    item_names(badge_candidates),
+                    anomaly(badge_candidates, "badge candidate")
+                );
+            }
+            sum_badge_priority += priority_sum(badge_candidates);
+            badge_candidates = ALL_ITEMS;
+        }
+    }
+
+    if !n_rucksacks.is_multiple_of(layout.group_size) {
+        return Err(format!(
+            "d03: the last group has only {} of {} elves.",
+            n_rucksacks % layout.group_size,
+            layout.group_size
+        )
+        .into());
+    }
+
+    Ok((sum_priority, sum_badge_priority))
+}
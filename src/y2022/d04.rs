@@ -0,0 +1,357 @@
+use std::io::BufRead;
+
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let mut count_fully_contained: u64 = 0;
+    let mut count_partially_contained: u64 = 0;
+
+    for line in input.lines() {
+        if line.trim().chars().count() == 0 {
+            continue;
+        }
+        let sections = parse_sections(line)?;
+        if sections.len() != 2 {
+            return Err("d04: expected two sections per line".into());
+        }
+
+        if sections[0].fully_contained_within(&sections[1])
+            || sections[1].fully_contained_within(&sections[0])
+        {
+            count_fully_contained += 1;
+            count_partially_contained += 1;
+        } else if sections[0].overlaps_with(&sections[1]) {
+            count_partially_contained += 1;
+        }
+    }
+
+    let mut result = format!("Day 04/01: {}\n", count_fully_contained);
+    result += &format!("Day 04/02: {}\n", count_partially_contained);
+    Ok(result)
+}
+
+// Options: `--coverage` to list the sections covering each run of IDs,
+// `--relations` to count the Allen relations of the pairs on each line and
+// `--lines RELATION` to also list the lines with a pair in that relation. Lines
+// may assign any number of sections. Each section is named by its line and
+// position, so `3:2` is the second section on line 3.
+pub fn tool(input: &mut dyn BufRead, args: &[String]) -> Result<String, StringError> {
+    let mut list_coverage = false;
+    let mut count_relations = false;
+    let mut list_lines = None;
+
+    let mut args = args.iter();
+    while let Some(a) = args.next() {
+        match a.as_str() {
+            "--coverage" => list_coverage = true,
+            "--relations" => count_relations = true,
+            "--lines" => {
+                let name = args.next().ok_or("d04: --lines needs a relation.")?;
+                let relation = RELATIONS
+                    .into_iter()
+                    .find(|r| r.name() == name)
+                    .ok_or(format!("d04: unknown relation {}", name))?;
+                count_relations = true;
+                list_lines = Some(relation);
+            }
+            _ => return Err(format!("d04: unknown option {}", a).into()),
+        }
+    }
+
+    let mut content = String::new();
+    input
+        .read_to_string(&mut content)
+        .map_err(|_| "d04: could not read input.")?;
+
+    let mut assignments = Vec::new();
+    let mut n_lines = 0;
+    for (i, line) in content.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        n_lines += 1;
+        for (k, section) in parse_sections(line)?.into_iter().enumerate() {
+            if section.lower > section.upper {
+                return Err(
+                    format!("d04: section {}:{} ends before it starts", i + 1, k + 1).into(),
+                );
+            }
+            assignments.push(Assignment {
+                line: i + 1,
+                position: k + 1,
+                section,
+            });
+        }
+    }
+
+    let runs = coverage(&assignments);
+    let (Some(first), Some(last)) = (runs.first(), runs.last()) else {
+        return Ok("No sections.\n".into());
+    };
+
+    let depth = runs.iter().map(|r| r.sections.len()).max().unwrap_or(0);
+    let id_ranges = |runs: Vec<&Run>| {
+        runs.iter()
+            .map(|r| r.section.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    };
+    let deepest = runs.iter().filter(|r| r.sections.len() == depth).collect();
+    let uncovered: Vec<_> = runs.iter().filter(|r| r.sections.is_empty()).collect();
+
+    let mut result = format!("Sections: {} on {} lines\n", assignments.len(), n_lines);
+    result += &format!(
+        "IDs: {}\n",
+        Section::new(first.section.lower, last.section.upper)
+    );
+    result += &format!(
+        "Max overlap depth: {} at IDs {}\n",
+        depth,
+        id_ranges(deepest)
+    );
+    if uncovered.is_empty() {
+        result += "Uncovered IDs: none\n";
+    } else {
+        result += &format!("Uncovered IDs: {}\n", id_ranges(uncovered));
+    }
+
+    if list_coverage {
+        for r in runs.iter() {
+            let names: Vec<_> = r
+                .sections
+                .iter()
+                .map(|&s| format!("{}:{}", assignments[s].line, assignments[s].position))
+                .collect();
+            if names.is_empty() {
+                result += &format!("IDs {}: none\n", r.section);
+            } else {
+                result += &format!("IDs {}: {}\n", r.section, names.join(", "));
+            }
+        }
+    }
+
+    if count_relations {
+        result += &relation_report(&assignments, list_lines);
+    }
+
+    Ok(result)
+}
+
+// Counts the relations of every pair of sections assigned on the same line,
+// in the order they are listed.
+fn relation_report(assignments: &[Assignment], list_lines: Option<Relation>) -> String {
+    let mut counts = [0; RELATIONS.len()];
+    let mut lines = Vec::new();
+
+    for (i, a) in assignments.iter().enumerate() {
+        for b in assignments[i + 1..].iter().take_while(|b| b.line == a.line) {
+            let relation = a.section.relation_to(&b.section);
+            counts[relation as usize] += 1;
+            if list_lines == Some(relation) && lines.last() != Some(&a.line) {
+                lines.push(a.line);
+            }
+        }
+    }
+
+    let mut result = format!("Relations of {} pairs:\n", counts.iter().sum::<usize>());
+    for r in RELATIONS {
+        result += &format!("{:>14} {:>6}\n", r.name(), counts[r as usize]);
+    }
+    if let Some(r) = list_lines {
+        result += &format!(
+            "Lines with {}: {}\n",
+            r.name(),
+            lines
+                .iter()
+                .map(|l| l.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    result
+}
+
+// Allen's interval relations, of a section to another one. A section of IDs
+// is taken to span from the start of its first ID to the end of its last, so
+// `2-4` meets `5-7` while `2-4` and `4-6` overlap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Relation {
+    Before,
+    Meets,
+    Overlaps,
+    Starts,
+    During,
+    Finishes,
+    Equals,
+    After,
+    MetBy,
+    OverlappedBy,
+    StartedBy,
+    Contains,
+    FinishedBy,
+}
+
+const RELATIONS: [Relation; 13] = [
+    Relation::Before,
+    Relation::Meets,
+    Relation::Overlaps,
+    Relation::Starts,
+    Relation::During,
+    Relation::Finishes,
+    Relation::Equals,
+    Relation::After,
+    Relation::MetBy,
+    Relation::OverlappedBy,
+    Relation::StartedBy,
+    Relation::Contains,
+    Relation::FinishedBy,
+];
+
+impl Relation {
+    fn name(&self) -> &'static str {
+        match self {
+            Relation::Before => "before",
+            Relation::Meets => "meets",
+            Relation::Overlaps => "overlaps",
+            Relation::Starts => "starts",
+            Relation::During => "during",
+            Relation::Finishes => "finishes",
+            Relation::Equals => "equals",
+            Relation::After => "after",
+            Relation::MetBy => "met-by",
+            Relation::OverlappedBy => "overlapped-by",
+            Relation::StartedBy => "started-by",
+            Relation::Contains => "contains",
+            Relation::FinishedBy => "finished-by",
+        }
+    }
+}
+
+// A section together with where it was assigned.
+struct Assignment {
+    line: usize,
+    position: usize,
+    section: Section,
+}
+
+// Consecutive IDs covered by the same sections, given as indices into the
+// assignments.
+struct Run {
+    section: Section,
+    sections: Vec<usize>,
+}
+
+// Splits the IDs from the lowest to the highest assigned one into runs. The
+// coverage can only change where a section starts or right after one ends, so
+// only these IDs are looked at, however large the sections are.
+fn coverage(assignments: &[Assignment]) -> Vec<Run> {
+    let mut boundaries: Vec<i64> = assignments
+        .iter()
+        .flat_map(|a| [a.section.lower, a.section.upper + 1])
+        .collect();
+    boundaries.sort_unstable();
+    boundaries.dedup();
+
+    boundaries
+        .windows(2)
+        .map(|w| Run {
+            section: Section::new(w[0], w[1] - 1),
+            sections: (0..assignments.len())
+                .filter(|&i| assignments[i].section.contains(w[0]))
+                .collect(),
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+struct Section {
+    lower: i64,
+    upper: i64,
+}
+
+impl Section {
+    fn new(lower: i64, upper: i64) -> Self {
+        Section { lower, upper }
+    }
+
+    fn fully_contained_within(&self, other: &Self) -> bool {
+        self.lower >= other.lower && self.upper <= other.upper
+    }
+
+    // Expects both sections to end no earlier than they start.
+    fn relation_to(&self, other: &Self) -> Relation {
+        let (start, end) = (self.lower, self.upper + 1);
+        let (other_start, other_end) = (other.lower, other.upper + 1);
+
+        if end < other_start {
+            Relation::Before
+        } else if end == other_start {
+            Relation::Meets
+        } else if other_end < start {
+            Relation::After
+        } else if other_end == start {
+            Relation::MetBy
+        } else if start == other_start && end == other_end {
+            Relation::Equals
+        } else if start == other_start {
+            if end < other_end {
+                Relation::Starts
+            } else {
+                Relation::StartedBy
+            }
+        } else if end == other_end {
+            if start > other_start {
+                Relation::Finishes
+            } else {
+                Relation::FinishedBy
+            }
+        } else if start > other_start && end < other_end {
+            Relation::During
+        } else if start < other_start && end > other_end {
+            Relation::Contains
+        } else if start < other_start {
+            Relation::Overlaps
+        } else {
+            Relation::OverlappedBy
+        }
+    }
+
+    fn contains(&self, id: i64) -> bool {
+        self.lower <= id && id <= self.upper
+    }
+
+    fn overlaps_with(&self, other: &Self) -> bool {
+        let lower_between = other.lower <= self.lower && self.lower <= other.upper;
+        let upper_between = other.lower <= self.upper && self.upper <= other.upper;
+        lower_between || upper_between
+    }
+}
+
+// `5` for a single ID, `2-4` otherwise.
+impl std::fmt::Display for Section {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.lower == self.upper {
+            write!(f, "{}", self.lower)
+        } else {
+            write!(f, "{}-{}", self.lower, self.upper)
+        }
+    }
+}
+
+// Sections are separated by commas, each one is a range like `2-4`.
+fn parse_sections(s: &str) -> Result<Vec<Section>, StringError> {
+    let parse_id = |s: &str| {
+        s.trim()
+            .parse::<i64>()
+            .map_err(|_| StringError::from("d04: invalid section ID"))
+    };
+
+    s.split(',')
+        .map(|range| {
+            let (lower, upper) = range
+                .split_once('-')
+                .ok_or("d04: expected a range of section IDs")?;
+            Ok(Section::new(parse_id(lower)?, parse_id(upper)?))
+        })
+        .collect()
+}
@@ -101,12 +101,11 @@ impl Towers {
         } else {
             self.heights[from_col] - n_crates
         };
-        let mut j = self.heights[to_col];
+        let j_start = self.heights[to_col];
 
-        for _ in 0..n_crates {
+        for j in j_start..(j_start + n_crates) {
             self.cells[j * TOWER_WIDTH + to_col] = self.cells[i * TOWER_WIDTH + from_col];
             self.cells[i * TOWER_WIDTH + from_col] = CrateCell::Empty;
-            j += 1;
 
             if self.is_9000 && i > 0 {
                 i -= 1;
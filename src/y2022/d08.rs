@@ -42,12 +42,14 @@ impl Tree {
 
         let mut blocked_view = false;
         let mut max_view = distance_from_border - 1;
-        for h_index in (self.height as usize)..10 {
-            if let Some(d) = distances_from_heights[h_index] {
-                blocked_view = true;
-                if max_view > d {
-                    max_view = d;
-                }
+        for d in distances_from_heights
+            .iter()
+            .skip(self.height as usize)
+            .flatten()
+        {
+            blocked_view = true;
+            if max_view > *d {
+                max_view = *d;
             }
         }
 
@@ -1,5 +1,6 @@
 use std::collections::VecDeque;
 
+use crate::expression::{Operation, Value};
 use crate::string_error::StringError;
 
 pub fn solve(input: &str) -> Result<String, StringError> {
@@ -8,10 +9,10 @@ pub fn solve(input: &str) -> Result<String, StringError> {
     let mut monkeys = parse_input(input)?;
     let mut monkeys2 = monkeys.clone();
     for _ in 0..20 {
-        monkeys.round(true);
+        monkeys.round(true)?;
     }
     for _ in 0..10000 {
-        monkeys2.round(false);
+        monkeys2.round(false)?;
     }
 
     let mut result = format!("Day 11/01: {}\n", monkeys.monkey_business());
@@ -23,47 +24,37 @@ pub fn solve(input: &str) -> Result<String, StringError> {
 struct Monkey {
     n_turns: usize,
     items: VecDeque<i64>,
-    operation: Operation,
+    operation: Operation<Old>,
     divisor_for_test: i64,
     true_destination: usize,
     false_destination: usize,
 }
 
 trait MonkeyBusinessable {
-    fn inspect(&mut self, i: usize, make_manageable: bool);
-    fn round(&mut self, make_manageable: bool);
+    fn inspect(&mut self, i: usize, make_manageable: bool) -> Result<(), StringError>;
+    fn round(&mut self, make_manageable: bool) -> Result<(), StringError>;
     fn monkey_business(&self) -> usize;
 }
 
 impl MonkeyBusinessable for Vec<Monkey> {
-    fn inspect(&mut self, i: usize, make_manageable: bool) {
+    fn inspect(&mut self, i: usize, make_manageable: bool) -> Result<(), StringError> {
+        // Reducing right after each inspection (not only once per round) keeps
+        // items thrown along several monkeys within a round from overflowing.
+        let p = self.iter().map(|m| m.divisor_for_test).product::<i64>();
+
         while !self[i].items.is_empty() {
             let worry_level = self[i].items.pop_front().unwrap();
             self[i].n_turns += 1;
 
-            let new_worry_level_intermediate = match self[i].operation {
-                Operation::Add(Value::Old, Value::Old) => worry_level + worry_level,
-                Operation::Add(Value::Literal(c), Value::Old) => c + worry_level,
-                Operation::Add(Value::Old, Value::Literal(c)) => worry_level + c,
-                Operation::Add(Value::Literal(c), Value::Literal(d)) => c + d,
-                Operation::Sub(Value::Old, Value::Old) => 0,
-                Operation::Sub(Value::Literal(c), Value::Old) => c - worry_level,
-                Operation::Sub(Value::Old, Value::Literal(c)) => worry_level - c,
-                Operation::Sub(Value::Literal(c), Value::Literal(d)) => c - d,
-                Operation::Mul(Value::Old, Value::Old) => worry_level * worry_level,
-                Operation::Mul(Value::Literal(c), Value::Old) => c * worry_level,
-                Operation::Mul(Value::Old, Value::Literal(c)) => worry_level * c,
-                Operation::Mul(Value::Literal(c), Value::Literal(d)) => c * d,
-                Operation::Div(Value::Old, Value::Old) => 1,
-                Operation::Div(Value::Literal(c), Value::Old) => c / worry_level,
-                Operation::Div(Value::Old, Value::Literal(c)) => worry_level / c,
-                Operation::Div(Value::Literal(c), Value::Literal(d)) => c / d,
-            };
+            let new_worry_level_intermediate = self[i]
+                .operation
+                .evaluate(|Old| worry_level)
+                .ok_or("d11: worry level overflow.")?;
 
             let new_worry_level = if make_manageable {
                 new_worry_level_intermediate / 3
             } else {
-                new_worry_level_intermediate
+                new_worry_level_intermediate % p
             };
 
             let divisor = self[i].divisor_for_test;
@@ -75,20 +66,15 @@ impl MonkeyBusinessable for Vec<Monkey> {
                 self[j].items.push_back(new_worry_level);
             }
         }
+
+        Ok(())
     }
 
-    fn round(&mut self, make_manageable: bool) {
+    fn round(&mut self, make_manageable: bool) -> Result<(), StringError> {
         for i in 0..self.len() {
-            self.inspect(i, make_manageable);
-        }
-
-        if !make_manageable {
-            let p = self.iter().map(|m| m.divisor_for_test).product::<i64>();
-            self.iter_mut()
-                .flat_map(|m| m.items.iter_mut())
-                .map(|w| *w %= p)
-                .count();
+            self.inspect(i, make_manageable)?;
         }
+        Ok(())
     }
 
     fn monkey_business(&self) -> usize {
@@ -105,19 +91,9 @@ impl MonkeyBusinessable for Vec<Monkey> {
     }
 }
 
+// The only variable in the notes: the worry level before the operation.
 #[derive(Debug, Clone, Copy)]
-enum Value {
-    Old,
-    Literal(i64),
-}
-
-#[derive(Debug, Clone, Copy)]
-enum Operation {
-    Add(Value, Value),
-    Sub(Value, Value),
-    Mul(Value, Value),
-    Div(Value, Value),
-}
+struct Old;
 
 fn parse_input(input: &str) -> Result<Vec<Monkey>, StringError> {
     let check_monkey_number = |line: &str, target: usize| -> Result<(), StringError> {
@@ -164,9 +140,9 @@ fn parse_input(input: &str) -> Result<Vec<Monkey>, StringError> {
         Ok(result)
     };
 
-    let string_to_value = |s: &str| -> Result<Value, StringError> {
+    let string_to_value = |s: &str| -> Result<Value<Old>, StringError> {
         if s.trim() == "old" {
-            return Ok(Value::Old);
+            return Ok(Value::Variable(Old));
         }
 
         let v = s
@@ -177,7 +153,7 @@ fn parse_input(input: &str) -> Result<Vec<Monkey>, StringError> {
         Ok(Value::Literal(v))
     };
 
-    let parse_operation = |line: &str| -> Result<Operation, StringError> {
+    let parse_operation = |line: &str| -> Result<Operation<Old>, StringError> {
         if !line.starts_with("Operation: new = ") {
             return Err("d11: Invalid input. Line should start with \"Operation: new = \"".into());
         }
@@ -198,13 +174,8 @@ fn parse_input(input: &str) -> Result<Vec<Monkey>, StringError> {
         let left = string_to_value(first)?;
         let right = string_to_value(third)?;
 
-        match second.trim() {
-            "+" => Ok(Operation::Add(left, right)),
-            "-" => Ok(Operation::Sub(left, right)),
-            "*" => Ok(Operation::Mul(left, right)),
-            "/" => Ok(Operation::Div(left, right)),
-            _ => Err("d11: Unknown Operation.".into()),
-        }
+        Operation::from_symbol(second.trim(), left, right)
+            .ok_or_else(|| "d11: Unknown Operation.".into())
     };
 
     let parse_test = |line: &str| -> Result<i64, StringError> {
@@ -1,5 +1,6 @@
 use std::collections::{BTreeSet, VecDeque};
 
+use crate::grid::neighbors;
 use crate::string_error::StringError;
 
 pub fn solve(input: &str) -> Result<String, StringError> {
@@ -224,67 +225,3 @@ impl HeightMap {
         ))
     }
 }
-
-fn neighbors(i: usize, j: usize, height: usize, width: usize) -> NeighborIter {
-    NeighborIter {
-        i,
-        j,
-        width,
-        height,
-        dir: 0,
-    }
-}
-
-struct NeighborIter {
-    i: usize,
-    j: usize,
-    width: usize,
-    height: usize,
-    dir: u8,
-}
-
-impl Iterator for NeighborIter {
-    type Item = (usize, usize);
-
-    fn next(&mut self) -> Option<Self::Item> {
-        match self.dir {
-            0 => {
-                if self.j + 1 < self.width {
-                    self.dir += 1;
-                    Some((self.i, self.j + 1))
-                } else {
-                    self.dir += 1;
-                    self.next()
-                }
-            }
-            1 => {
-                if self.i > 0 {
-                    self.dir += 1;
-                    Some((self.i - 1, self.j))
-                } else {
-                    self.dir += 1;
-                    self.next()
-                }
-            }
-            2 => {
-                if self.j > 0 {
-                    self.dir += 1;
-                    Some((self.i, self.j - 1))
-                } else {
-                    self.dir += 1;
-                    self.next()
-                }
-            }
-            3 => {
-                if self.i + 1 < self.height {
-                    self.dir += 1;
-                    Some((self.i + 1, self.j))
-                } else {
-                    self.dir += 1;
-                    self.next()
-                }
-            }
-            _ => None,
-        }
-    }
-}
@@ -170,7 +170,7 @@ impl Packet {
 
 impl std::cmp::PartialOrd for Packet {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp_func(other))
+        Some(self.cmp(other))
     }
 }
 
@@ -180,7 +180,7 @@ impl std::cmp::Ord for Packet {
     }
 }
 
-fn twinlines(s: &str) -> TwinlineIter {
+fn twinlines(s: &str) -> TwinlineIter<'_> {
     TwinlineIter {
         lines: s.lines(),
         prev: None,
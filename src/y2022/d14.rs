@@ -1,5 +1,6 @@
 use std::collections::BTreeSet;
 
+use crate::point::Point;
 use crate::string_error::StringError;
 
 pub fn solve(input: &str) -> Result<String, StringError> {
@@ -81,17 +82,7 @@ pub fn solve(input: &str) -> Result<String, StringError> {
     Ok(result)
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
-struct Point {
-    x: i64,
-    y: i64,
-}
-
 impl Point {
-    fn new(x: i64, y: i64) -> Self {
-        Self { x, y }
-    }
-
     fn from_str(s: &str) -> Self {
         let mut k = s.split(',');
         let first = k
@@ -143,7 +134,7 @@ impl<'a> Iterator for StrPointIter<'a> {
     }
 }
 
-fn iter_points(input: &str) -> StrPointIter {
+fn iter_points(input: &str) -> StrPointIter<'_> {
     let mut lines = input.lines();
     let first_line = lines.next();
 
@@ -1,8 +1,13 @@
+use std::collections::BTreeSet;
 use std::str::FromStr;
 
 use crate::string_error::StringError;
 
 pub fn solve(input: &str) -> Result<String, StringError> {
+    solve_with_bounds(input, 2_000_000, 4_000_000)
+}
+
+pub fn solve_with_bounds(input: &str, row_part1: i64, xy_max: i64) -> Result<String, StringError> {
     let input = input.trim();
 
     let mut beacons = Vec::new();
@@ -17,37 +22,36 @@ pub fn solve(input: &str) -> Result<String, StringError> {
         beacons.push(beacon);
     }
 
-    let row_part1 = 2_000_000;
-    // Find at least on impossible lattice point.
-    let mut x_init_part1 = None;
+    // Every sensor reaching the row lies in a run of impossible lattice
+    // points. The runs need not be contiguous, so collect all of them.
+    let mut runs = BTreeSet::new();
 
     for b in beacons.iter() {
         let dy = (b.position.y - row_part1).abs();
         let d = b.position.manhatten_distance(&b.closest_beacon);
         if d >= dy {
-            x_init_part1 = Some(b.position.x);
-            break;
+            let p = Point::new(b.position.x, row_part1);
+            let p_right = step_right_if_overlap_slice(p, &beacons);
+            let p_left = step_left_if_overlap_slice(p, &beacons);
+            runs.insert((p_left.x, p_right.x));
         }
     }
 
-    let mut solution1: i64 = 0;
-    if let Some(x) = x_init_part1 {
-        let p = Point::new(x, row_part1);
-        let p_right = step_right_if_overlap_slice(p, &beacons);
-        let p_left = step_left_if_overlap_slice(p, &beacons);
-
-        solution1 = p_right.x - p_left.x - 2;
+    let mut solution1: i64 = runs.iter().map(|(l, r)| r - l - 1).sum();
 
-        for b in beacons.iter() {
-            let pb = b.closest_beacon;
-            if pb.y != row_part1 {
-                continue;
-            }
+    let mut beacons_in_row = BTreeSet::new();
+    for b in beacons.iter() {
+        let pb = b.closest_beacon;
+        if pb.y != row_part1 {
+            continue;
+        }
+        if runs.iter().any(|(l, r)| *l < pb.x && pb.x < *r) {
+            beacons_in_row.insert(pb.x);
         }
     }
+    solution1 -= beacons_in_row.len() as i64;
 
     let mut solution2 = None;
-    let xy_max = 4_000_000;
     for xy in 0..=xy_max {
         let p = Point::new(xy, xy);
         let p_right = step_right_if_overlap_slice(p, &beacons);
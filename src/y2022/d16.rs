@@ -0,0 +1,219 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let input = input.trim();
+
+    let valves = parse_input(input)?;
+    let network = Network::from_valves(&valves)?;
+    let all_closed = (1u64 << network.flow_rates.len()) - 1;
+
+    let mut memo = HashMap::new();
+    let solution1 = network.max_pressure(network.start, 30, all_closed, &mut memo);
+
+    // The elephant and I open disjoint sets of valves. For every set, find
+    // the best pressure when opening at most those valves, then combine each
+    // set with its complement.
+    let mut best = vec![0; 1 << network.flow_rates.len()];
+    network.record_best(network.start, 26, 0, 0, &mut best);
+    for bit in (0..network.flow_rates.len()).map(|v| 1 << v) {
+        for mask in 0..best.len() {
+            if mask & bit != 0 {
+                best[mask] = best[mask].max(best[mask ^ bit]);
+            }
+        }
+    }
+
+    let solution2 = (0..best.len())
+        .map(|mask| best[mask] + best[all_closed as usize & !mask])
+        .max()
+        .unwrap_or(0);
+
+    let mut result = format!("Day 16/01: {}\n", solution1);
+    result += &format!("Day 16/02: {}\n", solution2);
+    Ok(result)
+}
+
+#[derive(Debug)]
+struct Valve<'a> {
+    name: &'a str,
+    flow_rate: u32,
+    tunnels: Vec<&'a str>,
+}
+
+// Only the valves with non-zero flow rate and the start valve remain. The
+// valves with flow are the indices 0..flow_rates.len(), the start comes last.
+#[derive(Debug)]
+struct Network {
+    start: usize,
+    flow_rates: Vec<u32>,
+    distances: Vec<Vec<u32>>,
+}
+
+impl Network {
+    fn from_valves(valves: &[Valve]) -> Result<Self, StringError> {
+        let mut index_by_name = BTreeMap::new();
+        for (i, v) in valves.iter().enumerate() {
+            if index_by_name.insert(v.name, i).is_some() {
+                return Err(format!("d16: valve {} defined twice.", v.name).into());
+            }
+        }
+
+        let mut neighbors = Vec::new();
+        for v in valves.iter() {
+            let mut n = Vec::new();
+            for t in v.tunnels.iter() {
+                let j = index_by_name
+                    .get(t)
+                    .ok_or(format!("d16: tunnel to unknown valve {}.", t))?;
+                n.push(*j);
+            }
+            neighbors.push(n);
+        }
+
+        let start = *index_by_name.get("AA").ok_or("d16: no valve AA.")?;
+
+        let mut kept: Vec<usize> = (0..valves.len())
+            .filter(|i| valves[*i].flow_rate > 0)
+            .collect();
+        if kept.len() > 24 {
+            return Err("d16: too many valves with non-zero flow rate.".into());
+        }
+        let flow_rates = kept.iter().map(|i| valves[*i].flow_rate).collect();
+        kept.push(start);
+
+        let distances = kept
+            .iter()
+            .map(|from| {
+                let d = bfs_distances(&neighbors, *from);
+                kept.iter().map(|to| d[*to]).collect()
+            })
+            .collect();
+
+        Ok(Self {
+            start: kept.len() - 1,
+            flow_rates,
+            distances,
+        })
+    }
+
+    // Most pressure that can still be released from `position` with
+    // `time_left` minutes, opening only valves in the `closed` bit mask.
+    fn max_pressure(
+        &self,
+        position: usize,
+        time_left: u32,
+        closed: u64,
+        memo: &mut HashMap<(usize, u32, u64), u32>,
+    ) -> u32 {
+        if let Some(p) = memo.get(&(position, time_left, closed)) {
+            return *p;
+        }
+
+        let mut best = 0;
+        for (v, flow_rate) in self.flow_rates.iter().enumerate() {
+            if closed & (1 << v) == 0 {
+                continue;
+            }
+
+            // Walking there and opening the valve.
+            let cost = self.distances[position][v].saturating_add(1);
+            if cost >= time_left {
+                continue;
+            }
+
+            let t = time_left - cost;
+            let p = flow_rate * t + self.max_pressure(v, t, closed & !(1 << v), memo);
+            best = best.max(p);
+        }
+
+        memo.insert((position, time_left, closed), best);
+        best
+    }
+
+    // Visits every order of opening valves and stores, for each set of
+    // opened valves, the most pressure released by opening exactly those.
+    fn record_best(
+        &self,
+        position: usize,
+        time_left: u32,
+        opened: usize,
+        pressure: u32,
+        best: &mut [u32],
+    ) {
+        best[opened] = best[opened].max(pressure);
+
+        for (v, flow_rate) in self.flow_rates.iter().enumerate() {
+            if opened & (1 << v) != 0 {
+                continue;
+            }
+
+            let cost = self.distances[position][v].saturating_add(1);
+            if cost >= time_left {
+                continue;
+            }
+
+            let t = time_left - cost;
+            self.record_best(v, t, opened | (1 << v), pressure + flow_rate * t, best);
+        }
+    }
+}
+
+// Unreachable valves get u32::MAX.
+fn bfs_distances(neighbors: &[Vec<usize>], from: usize) -> Vec<u32> {
+    let mut distances = vec![u32::MAX; neighbors.len()];
+    distances[from] = 0;
+
+    let mut todo = VecDeque::new();
+    todo.push_back(from);
+
+    while let Some(i) = todo.pop_front() {
+        for j in neighbors[i].iter() {
+            if distances[*j] == u32::MAX {
+                distances[*j] = distances[i] + 1;
+                todo.push_back(*j);
+            }
+        }
+    }
+
+    distances
+}
+
+fn parse_input(input: &str) -> Result<Vec<Valve<'_>>, StringError> {
+    let mut result = Vec::new();
+
+    for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        if !line.starts_with("Valve ") {
+            return Err("d16: Invalid input. Line should start with \"Valve \"".into());
+        }
+        let trimmed = line.trim_start_matches("Valve ");
+
+        let (name, rest) = trimmed
+            .split_once(" has flow rate=")
+            .ok_or("d16: Invalid input. Expected \" has flow rate=\"")?;
+
+        let (flow_rate, rest) = rest
+            .split_once(';')
+            .ok_or("d16: Invalid input. Expected \";\" after flow rate")?;
+        let flow_rate = flow_rate
+            .parse::<u32>()
+            .map_err(|_| "d16: could not parse flow rate.")?;
+
+        let tunnels = if let Some(t) = rest.strip_prefix(" tunnels lead to valves ") {
+            t.split(',').map(|s| s.trim()).collect()
+        } else if let Some(t) = rest.strip_prefix(" tunnel leads to valve ") {
+            vec![t.trim()]
+        } else {
+            return Err("d16: Invalid input. Could not find tunnels.".into());
+        };
+
+        result.push(Valve {
+            name,
+            flow_rate,
+            tunnels,
+        });
+    }
+
+    Ok(result)
+}
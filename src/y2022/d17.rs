@@ -0,0 +1,153 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::point::Point;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let input = input.trim();
+
+    let mut jets = Vec::new();
+    for c in input.chars() {
+        match c {
+            '<' => jets.push(-1),
+            '>' => jets.push(1),
+            _ => return Err("d17: Invalid input char.".into()),
+        }
+    }
+    if jets.is_empty() {
+        return Err("d17: received no input.".into());
+    }
+
+    let mut chamber = Chamber::new(jets);
+    let heights = chamber.heights_until_cycle();
+
+    let mut result = format!("Day 17/01: {}\n", heights.height_after(2022));
+    result += &format!("Day 17/02: {}\n", heights.height_after(1_000_000_000_000));
+    Ok(result)
+}
+
+const CHAMBER_WIDTH: i64 = 7;
+
+// Rows below the surface that are part of the cycle detection state.
+const PROFILE_DEPTH: i64 = 64;
+
+// Rock cells relative to their lower left corner, y pointing up.
+const ROCKS: [&[(i64, i64)]; 5] = [
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+    &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    &[(0, 0), (1, 0), (0, 1), (1, 1)],
+];
+
+// Next rock, next jet and how deep each column's top lies below the surface.
+type State = (usize, usize, [i64; CHAMBER_WIDTH as usize]);
+
+struct Chamber {
+    jets: Vec<i64>,
+    next_jet: usize,
+    // Settled rock cells, the floor is at y = -1.
+    cells: BTreeSet<Point>,
+    height: i64,
+}
+
+impl Chamber {
+    fn new(jets: Vec<i64>) -> Self {
+        Self {
+            jets,
+            next_jet: 0,
+            cells: BTreeSet::new(),
+            height: 0,
+        }
+    }
+
+    fn is_free(&self, p: Point) -> bool {
+        p.x >= 0 && p.x < CHAMBER_WIDTH && p.y >= 0 && !self.cells.contains(&p)
+    }
+
+    fn fits(&self, rock: &[(i64, i64)], corner: Point) -> bool {
+        rock.iter()
+            .all(|(dx, dy)| self.is_free(corner + Point::new(*dx, *dy)))
+    }
+
+    fn drop_rock(&mut self, rock: &[(i64, i64)]) {
+        let mut corner = Point::new(2, self.height + 3);
+
+        loop {
+            let pushed = corner + Point::new(self.jets[self.next_jet], 0);
+            self.next_jet = (self.next_jet + 1) % self.jets.len();
+            if self.fits(rock, pushed) {
+                corner = pushed;
+            }
+
+            let fallen = corner + Point::new(0, -1);
+            if !self.fits(rock, fallen) {
+                break;
+            }
+            corner = fallen;
+        }
+
+        for (dx, dy) in rock.iter() {
+            let p = corner + Point::new(*dx, *dy);
+            self.cells.insert(p);
+            self.height = self.height.max(p.y + 1);
+        }
+    }
+
+    fn state(&self, next_rock: usize) -> State {
+        let mut profile = [PROFILE_DEPTH; CHAMBER_WIDTH as usize];
+        for (x, depth) in profile.iter_mut().enumerate() {
+            for d in 0..PROFILE_DEPTH {
+                if !self.is_free(Point::new(x as i64, self.height - 1 - d)) {
+                    *depth = d;
+                    break;
+                }
+            }
+        }
+        (next_rock, self.next_jet, profile)
+    }
+
+    // Drops rocks until a state repeats. heights[n] is the tower height
+    // after n rocks.
+    fn heights_until_cycle(&mut self) -> Heights {
+        let mut heights = vec![0];
+        let mut seen: BTreeMap<State, usize> = BTreeMap::new();
+
+        loop {
+            let n = heights.len() - 1;
+            let next_rock = n % ROCKS.len();
+
+            if let Some(cycle_start) = seen.insert(self.state(next_rock), n) {
+                return Heights {
+                    heights,
+                    cycle_start,
+                    cycle_length: n - cycle_start,
+                };
+            }
+
+            self.drop_rock(ROCKS[next_rock]);
+            heights.push(self.height);
+        }
+    }
+}
+
+struct Heights {
+    heights: Vec<i64>,
+    cycle_start: usize,
+    cycle_length: usize,
+}
+
+impl Heights {
+    fn height_after(&self, n_rocks: usize) -> i64 {
+        if n_rocks < self.heights.len() {
+            return self.heights[n_rocks];
+        }
+
+        let cycle_growth =
+            self.heights[self.cycle_start + self.cycle_length] - self.heights[self.cycle_start];
+        let n_cycles = (n_rocks - self.cycle_start) / self.cycle_length;
+        let rest = (n_rocks - self.cycle_start) % self.cycle_length;
+
+        self.heights[self.cycle_start + rest] + n_cycles as i64 * cycle_growth
+    }
+}
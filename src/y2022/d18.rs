@@ -0,0 +1,89 @@
+use std::collections::BTreeSet;
+
+use crate::point::Point3;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let input = input.trim();
+
+    let mut cubes = BTreeSet::new();
+    for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        cubes.insert(parse_cube(line)?);
+    }
+    if cubes.is_empty() {
+        return Err("d18: received no input.".into());
+    }
+
+    let surface_area = cubes
+        .iter()
+        .flat_map(|c| c.neighbors())
+        .filter(|n| !cubes.contains(n))
+        .count();
+
+    let outside = flood_fill_outside(&cubes);
+    let exterior_surface_area = cubes
+        .iter()
+        .flat_map(|c| c.neighbors())
+        .filter(|n| outside.contains(n))
+        .count();
+
+    let mut result = format!("Day 18/01: {}\n", surface_area);
+    result += &format!("Day 18/02: {}\n", exterior_surface_area);
+    Ok(result)
+}
+
+// All air cells reachable from outside, within the bounding box grown by one
+// so that the water can flow around the droplet.
+fn flood_fill_outside(cubes: &BTreeSet<Point3>) -> BTreeSet<Point3> {
+    let lower = Point3::new(
+        cubes.iter().map(|c| c.x).min().unwrap() - 1,
+        cubes.iter().map(|c| c.y).min().unwrap() - 1,
+        cubes.iter().map(|c| c.z).min().unwrap() - 1,
+    );
+    let upper = Point3::new(
+        cubes.iter().map(|c| c.x).max().unwrap() + 1,
+        cubes.iter().map(|c| c.y).max().unwrap() + 1,
+        cubes.iter().map(|c| c.z).max().unwrap() + 1,
+    );
+    let in_box = |p: &Point3| {
+        lower.x <= p.x
+            && p.x <= upper.x
+            && lower.y <= p.y
+            && p.y <= upper.y
+            && lower.z <= p.z
+            && p.z <= upper.z
+    };
+
+    let mut outside = BTreeSet::new();
+    outside.insert(lower);
+    let mut todo = vec![lower];
+
+    while let Some(p) = todo.pop() {
+        for n in p.neighbors() {
+            if in_box(&n) && !cubes.contains(&n) && outside.insert(n) {
+                todo.push(n);
+            }
+        }
+    }
+
+    outside
+}
+
+fn parse_cube(line: &str) -> Result<Point3, StringError> {
+    let mut coordinates = [0; 3];
+    let mut splitted = line.split(',');
+
+    for c in coordinates.iter_mut() {
+        *c = splitted
+            .next()
+            .ok_or("d18: cube needs three coordinates.")?
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| "d18: could not parse coordinate.")?;
+    }
+    if splitted.next().is_some() {
+        return Err("d18: cube has more than three coordinates.".into());
+    }
+
+    Ok(Point3::new(coordinates[0], coordinates[1], coordinates[2]))
+}
@@ -0,0 +1,225 @@
+use std::thread;
+
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let input = input.trim();
+
+    let blueprints = parse_input(input)?;
+    if blueprints.is_empty() {
+        return Err("d19: received no input.".into());
+    }
+
+    let geodes24 = max_geodes_parallel(&blueprints, 24);
+    let quality_level: u32 = blueprints
+        .iter()
+        .zip(geodes24.iter())
+        .map(|(b, g)| b.id * g)
+        .sum();
+
+    let first_three = &blueprints[..blueprints.len().min(3)];
+    let geodes32 = max_geodes_parallel(first_three, 32);
+
+    let mut result = format!("Day 19/01: {}\n", quality_level);
+    result += &format!("Day 19/02: {}\n", geodes32.iter().product::<u32>());
+    Ok(result)
+}
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+#[derive(Debug, Clone)]
+struct Blueprint {
+    id: u32,
+    // costs[robot][material], only ore, clay and obsidian are ever paid.
+    costs: [[u32; 3]; 4],
+}
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    time_left: u32,
+    robots: [u32; 4],
+    materials: [u32; 4],
+}
+
+impl Blueprint {
+    fn max_geodes(&self, minutes: u32) -> u32 {
+        // Only one robot is built per minute, so there is no use in producing
+        // more of a material per minute than the most expensive recipe needs.
+        let mut caps = [u32::MAX; 4];
+        for (m, cap) in caps.iter_mut().enumerate().take(3) {
+            *cap = self.costs.iter().map(|c| c[m]).max().unwrap();
+        }
+
+        let start = State {
+            time_left: minutes,
+            robots: [1, 0, 0, 0],
+            materials: [0; 4],
+        };
+
+        let mut best = 0;
+        self.search(start, &caps, &mut best);
+        best
+    }
+
+    // Branches on which robot to build next, waiting as long as needed for it.
+    fn search(&self, state: State, caps: &[u32; 4], best: &mut u32) {
+        let t = state.time_left;
+        let geodes_if_idle = state.materials[GEODE] + state.robots[GEODE] * t;
+        *best = (*best).max(geodes_if_idle);
+
+        // Optimistic bound: a new geode robot in every remaining minute.
+        if geodes_if_idle + t * t.saturating_sub(1) / 2 <= *best {
+            return;
+        }
+
+        for robot in (0..4).rev() {
+            if state.robots[robot] >= caps[robot] {
+                continue;
+            }
+
+            let Some(wait) = self.minutes_until_affordable(&state, robot) else {
+                continue;
+            };
+            // Building takes a minute and the robot has to work at least one.
+            if wait + 1 >= t {
+                continue;
+            }
+
+            let mut next = state;
+            next.time_left = t - wait - 1;
+            for m in 0..4 {
+                next.materials[m] += state.robots[m] * (wait + 1);
+            }
+            for (m, cost) in self.costs[robot].iter().enumerate() {
+                next.materials[m] -= cost;
+            }
+            next.robots[robot] += 1;
+
+            self.search(next, caps, best);
+        }
+    }
+
+    fn minutes_until_affordable(&self, state: &State, robot: usize) -> Option<u32> {
+        let mut wait = 0;
+        for (m, cost) in self.costs[robot].iter().enumerate() {
+            if *cost <= state.materials[m] {
+                continue;
+            }
+            if state.robots[m] == 0 {
+                return None;
+            }
+            let missing = cost - state.materials[m];
+            wait = wait.max(missing.div_ceil(state.robots[m]));
+        }
+        Some(wait)
+    }
+}
+
+fn max_geodes_parallel(blueprints: &[Blueprint], minutes: u32) -> Vec<u32> {
+    thread::scope(|s| {
+        let handles: Vec<_> = blueprints
+            .iter()
+            .map(|b| s.spawn(move || b.max_geodes(minutes)))
+            .collect();
+
+        handles
+            .into_iter()
+            .map(|h| h.join().expect("d19: blueprint thread panicked."))
+            .collect()
+    })
+}
+
+fn parse_input(input: &str) -> Result<Vec<Blueprint>, StringError> {
+    let check_blueprint_number = |note: &str, target: u32| -> Result<(), StringError> {
+        if !note.starts_with("Blueprint ") {
+            return Err("d19: Invalid input. Note should start with \"Blueprint \"".into());
+        }
+
+        let trimmed = note.trim_start_matches("Blueprint ");
+
+        let np = trimmed.parse::<u32>();
+
+        match np {
+            Ok(n) => {
+                if n == target {
+                    Ok(())
+                } else {
+                    Err("d19: Invalid input. Wrong blueprint number".into())
+                }
+            }
+            Err(_) => Err("d19: Invalid Input. Blueprint number not a number".into()),
+        }
+    };
+
+    let parse_costs = |note: &str, robot: &str| -> Result<[u32; 3], StringError> {
+        let prefix = format!("Each {} robot costs ", robot);
+        if !note.starts_with(&prefix) {
+            return Err(format!(
+                "d19: Invalid input. Note should start with \"{}\"",
+                prefix
+            )
+            .into());
+        }
+
+        let trimmed = note.trim_start_matches(&prefix);
+        let mut costs = [0; 3];
+
+        for cost in trimmed.split(" and ") {
+            let mut splitted = cost.split_whitespace();
+            let amount = splitted
+                .next()
+                .ok_or("d19: Cost not enough arguments.")?
+                .parse::<u32>()
+                .map_err(|_| "d19: could not parse cost.")?;
+            let material = splitted.next().ok_or("d19: Cost not enough arguments.")?;
+
+            match material {
+                "ore" => costs[ORE] += amount,
+                "clay" => costs[CLAY] += amount,
+                "obsidian" => costs[OBSIDIAN] += amount,
+                _ => return Err("d19: Unknown material.".into()),
+            }
+        }
+
+        Ok(costs)
+    };
+
+    // Blueprints may be wrapped over several lines, so work on the notes
+    // between ':' and '.' instead of lines.
+    let mut note_iter = input
+        .split([':', '.'])
+        .map(|n| n.split_whitespace().collect::<Vec<_>>().join(" "))
+        .filter(|n| !n.is_empty());
+
+    let mut expected_next_blueprint_number = 1;
+    let mut result = Vec::new();
+
+    loop {
+        let note = note_iter.next();
+        if note.is_none() {
+            return Ok(result);
+        }
+        let note = note.unwrap();
+
+        check_blueprint_number(&note, expected_next_blueprint_number)?;
+
+        let mut costs = [[0; 3]; 4];
+        for (robot, name) in ["ore", "clay", "obsidian", "geode"].iter().enumerate() {
+            let note = note_iter.next().ok_or(format!(
+                "d19: not enough notes for Blueprint {}",
+                expected_next_blueprint_number
+            ))?;
+            costs[robot] = parse_costs(&note, name)?;
+        }
+
+        result.push(Blueprint {
+            id: expected_next_blueprint_number,
+            costs,
+        });
+
+        expected_next_blueprint_number += 1;
+    }
+}
@@ -0,0 +1,60 @@
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let input = input.trim();
+
+    let mut numbers = Vec::new();
+    for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        let v = line
+            .parse::<i64>()
+            .map_err(|_| "d20: could not parse number.")?;
+        numbers.push(v);
+    }
+    if numbers.iter().filter(|v| **v == 0).count() != 1 {
+        return Err("d20: input needs exactly one 0.".into());
+    }
+
+    let solution1 = grove_coordinates(&numbers, 1);
+
+    let decrypted: Vec<i64> = numbers.iter().map(|v| v * DECRYPTION_KEY).collect();
+    let solution2 = grove_coordinates(&decrypted, 10);
+
+    let mut result = format!("Day 20/01: {}\n", solution1);
+    result += &format!("Day 20/02: {}\n", solution2);
+    Ok(result)
+}
+
+const DECRYPTION_KEY: i64 = 811_589_153;
+
+fn grove_coordinates(numbers: &[i64], rounds: usize) -> i64 {
+    let order = mix(numbers, rounds);
+
+    let zero = order.iter().position(|i| numbers[*i] == 0).unwrap();
+    [1000, 2000, 3000]
+        .iter()
+        .map(|offset| numbers[order[(zero + offset) % order.len()]])
+        .sum()
+}
+
+// Returns the original indices of the numbers in their mixed order. Working
+// on indices keeps duplicate values apart.
+fn mix(numbers: &[i64], rounds: usize) -> Vec<usize> {
+    let mut order: Vec<usize> = (0..numbers.len()).collect();
+    if numbers.len() < 2 {
+        return order;
+    }
+
+    // While a number moves, the others form a circle one shorter.
+    let m = numbers.len() as i64 - 1;
+
+    for _ in 0..rounds {
+        for (i, v) in numbers.iter().enumerate() {
+            let from = order.iter().position(|j| *j == i).unwrap();
+            order.remove(from);
+            let to = (from as i64 + v).rem_euclid(m) as usize;
+            order.insert(to, i);
+        }
+    }
+
+    order
+}
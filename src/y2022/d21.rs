@@ -0,0 +1,189 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::expression::{Operation, Value};
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let input = input.trim();
+
+    let monkeys = Monkeys {
+        jobs: parse_input(input)?,
+    };
+
+    let mut values = BTreeMap::new();
+    let solution1 = monkeys.evaluate(ROOT, &mut values, &mut BTreeSet::new())?;
+
+    let mut result = format!("Day 21/01: {}\n", solution1);
+    result += &format!("Day 21/02: {}\n", monkeys.solve_for_human()?);
+    Ok(result)
+}
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+#[derive(Debug)]
+enum Job<'a> {
+    Number(i64),
+    Operation(Operation<&'a str>),
+}
+
+struct Monkeys<'a> {
+    jobs: BTreeMap<&'a str, Job<'a>>,
+}
+
+impl<'a> Monkeys<'a> {
+    fn job(&self, name: &str) -> Result<&Job<'a>, StringError> {
+        self.jobs
+            .get(name)
+            .ok_or_else(|| format!("d21: unknown monkey {}.", name).into())
+    }
+
+    // `visiting` holds the monkeys whose value is being computed, to detect
+    // cycles instead of recursing forever.
+    fn evaluate(
+        &self,
+        name: &'a str,
+        values: &mut BTreeMap<&'a str, i64>,
+        visiting: &mut BTreeSet<&'a str>,
+    ) -> Result<i64, StringError> {
+        if let Some(v) = values.get(name) {
+            return Ok(*v);
+        }
+        if !visiting.insert(name) {
+            return Err(format!("d21: monkey {} depends on itself.", name).into());
+        }
+
+        let v = match self.job(name)? {
+            Job::Number(n) => *n,
+            Job::Operation(operation) => {
+                let (l, r) = operation.operands();
+                let l = self.evaluate_value(l, values, visiting)?;
+                let r = self.evaluate_value(r, values, visiting)?;
+                operation.apply(l, r).ok_or(format!(
+                    "d21: monkey {} overflows or divides by zero.",
+                    name
+                ))?
+            }
+        };
+
+        visiting.remove(name);
+        values.insert(name, v);
+        Ok(v)
+    }
+
+    fn evaluate_value(
+        &self,
+        value: &Value<&'a str>,
+        values: &mut BTreeMap<&'a str, i64>,
+        visiting: &mut BTreeSet<&'a str>,
+    ) -> Result<i64, StringError> {
+        match value {
+            Value::Variable(name) => self.evaluate(name, values, visiting),
+            Value::Literal(c) => Ok(*c),
+        }
+    }
+
+    fn depends_on_human(&self, value: &Value<&'a str>) -> Result<bool, StringError> {
+        let name = match value {
+            Value::Variable(name) => *name,
+            Value::Literal(_) => return Ok(false),
+        };
+        if name == HUMAN {
+            return Ok(true);
+        }
+
+        match self.job(name)? {
+            Job::Number(_) => Ok(false),
+            Job::Operation(operation) => {
+                let (l, r) = operation.operands();
+                Ok(self.depends_on_human(l)? || self.depends_on_human(r)?)
+            }
+        }
+    }
+
+    // Root compares its operands for equality. Starting with the value of the
+    // side without the human, invert the operations down to the human.
+    fn solve_for_human(&self) -> Result<i64, StringError> {
+        let Job::Operation(root) = self.job(ROOT)? else {
+            return Err("d21: root has no operation.".into());
+        };
+
+        let mut values = BTreeMap::new();
+        let mut visiting = BTreeSet::new();
+
+        let (l, r) = root.operands();
+        let (mut current, mut target) = match (self.depends_on_human(l)?, self.depends_on_human(r)?)
+        {
+            (true, false) => (*l, self.evaluate_value(r, &mut values, &mut visiting)?),
+            (false, true) => (*r, self.evaluate_value(l, &mut values, &mut visiting)?),
+            _ => return Err("d21: humn has to be on exactly one side of root.".into()),
+        };
+
+        while current != Value::Variable(HUMAN) {
+            let Value::Variable(name) = current else {
+                unreachable!();
+            };
+            let Job::Operation(operation) = self.job(name)? else {
+                unreachable!();
+            };
+
+            let (l, r) = operation.operands();
+            let no_solution = || format!("d21: no integer solution at monkey {}.", name);
+            (current, target) = match (self.depends_on_human(l)?, self.depends_on_human(r)?) {
+                (true, false) => {
+                    let right = self.evaluate_value(r, &mut values, &mut visiting)?;
+                    let t = operation
+                        .solve_left(target, right)
+                        .ok_or_else(no_solution)?;
+                    (*l, t)
+                }
+                (false, true) => {
+                    let left = self.evaluate_value(l, &mut values, &mut visiting)?;
+                    let t = operation
+                        .solve_right(target, left)
+                        .ok_or_else(no_solution)?;
+                    (*r, t)
+                }
+                _ => return Err(format!("d21: humn used twice below monkey {}.", name).into()),
+            };
+        }
+
+        Ok(target)
+    }
+}
+
+fn parse_input(input: &str) -> Result<BTreeMap<&str, Job<'_>>, StringError> {
+    let mut result = BTreeMap::new();
+
+    for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        let (name, job) = line
+            .split_once(": ")
+            .ok_or("d21: Invalid input. Expected \": \" after monkey name")?;
+
+        let parts: Vec<&str> = job.split_whitespace().collect();
+        let job = match parts[..] {
+            [n] => Job::Number(
+                n.parse::<i64>()
+                    .map_err(|_| "d21: could not parse number.")?,
+            ),
+            [left, symbol, right] => Job::Operation(
+                Operation::from_symbol(symbol, string_to_value(left), string_to_value(right))
+                    .ok_or("d21: Unknown Operation.")?,
+            ),
+            _ => return Err(format!("d21: Invalid job for monkey {}.", name).into()),
+        };
+
+        if result.insert(name, job).is_some() {
+            return Err(format!("d21: monkey {} defined twice.", name).into());
+        }
+    }
+
+    Ok(result)
+}
+
+fn string_to_value(s: &str) -> Value<&str> {
+    match s.parse::<i64>() {
+        Ok(c) => Value::Literal(c),
+        Err(_) => Value::Variable(s),
+    }
+}
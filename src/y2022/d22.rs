@@ -0,0 +1,335 @@
+use std::collections::{BTreeMap, VecDeque};
+
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    // Leading spaces of the board are significant, so only trim the end.
+    let input = input.trim_end();
+
+    let (board, path) = parse_input(input)?;
+    let cube = Cube::fold(&board)?;
+
+    let solution1 = board.walk(&path, |state| board.wrap_flat(state));
+    let solution2 = board.walk(&path, |state| cube.wrap(state));
+
+    let mut result = format!("Day 22/01: {}\n", solution1);
+    result += &format!("Day 22/02: {}\n", solution2);
+    Ok(result)
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Instruction {
+    Forward(usize),
+    Left,
+    Right,
+}
+
+// Facings in the order of the password: right, down, left, up.
+const DIRECTIONS: [(i64, i64); 4] = [(0, 1), (1, 0), (0, -1), (-1, 0)];
+
+#[derive(Debug, Clone, Copy)]
+struct State {
+    row: i64,
+    col: i64,
+    facing: usize,
+}
+
+struct Board {
+    // b' ' for positions not on the board.
+    tiles: Vec<Vec<u8>>,
+}
+
+impl Board {
+    fn tile(&self, row: i64, col: i64) -> u8 {
+        if row < 0 || col < 0 {
+            return b' ';
+        }
+        self.tiles
+            .get(row as usize)
+            .and_then(|r| r.get(col as usize))
+            .copied()
+            .unwrap_or(b' ')
+    }
+
+    fn start(&self) -> State {
+        let col = self.tiles[0].iter().position(|t| *t == b'.').unwrap();
+        State {
+            row: 0,
+            col: col as i64,
+            facing: 0,
+        }
+    }
+
+    // `wrap` gives the state after stepping off the board from `state`.
+    fn walk<F>(&self, path: &[Instruction], wrap: F) -> i64
+    where
+        F: Fn(State) -> State,
+    {
+        let mut state = self.start();
+
+        for instruction in path.iter() {
+            match instruction {
+                Instruction::Left => state.facing = (state.facing + 3) % 4,
+                Instruction::Right => state.facing = (state.facing + 1) % 4,
+                Instruction::Forward(n) => {
+                    for _ in 0..*n {
+                        let (dr, dc) = DIRECTIONS[state.facing];
+                        let mut next = State {
+                            row: state.row + dr,
+                            col: state.col + dc,
+                            facing: state.facing,
+                        };
+                        if self.tile(next.row, next.col) == b' ' {
+                            next = wrap(state);
+                        }
+                        if self.tile(next.row, next.col) == b'#' {
+                            break;
+                        }
+                        state = next;
+                    }
+                }
+            }
+        }
+
+        1000 * (state.row + 1) + 4 * (state.col + 1) + state.facing as i64
+    }
+
+    // Continues from the opposite end of the row or column.
+    fn wrap_flat(&self, state: State) -> State {
+        let (dr, dc) = DIRECTIONS[state.facing];
+        let mut result = state;
+        while self.tile(result.row - dr, result.col - dc) != b' ' {
+            result.row -= dr;
+            result.col -= dc;
+        }
+        result
+    }
+}
+
+type Vector = [i64; 3];
+
+fn neg(v: Vector) -> Vector {
+    [-v[0], -v[1], -v[2]]
+}
+
+fn dot(a: Vector, b: Vector) -> i64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn add(a: Vector, b: Vector) -> Vector {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(v: Vector, c: i64) -> Vector {
+    [v[0] * c, v[1] * c, v[2] * c]
+}
+
+// Orientation of a face of the net after folding: its outward normal and the
+// directions its columns and rows grow in.
+#[derive(Debug, Clone, Copy)]
+struct Face {
+    normal: Vector,
+    right: Vector,
+    down: Vector,
+}
+
+impl Face {
+    // The face reached by folding over the edge in `facing`.
+    fn folded(&self, facing: usize) -> Self {
+        let Self {
+            normal,
+            right,
+            down,
+        } = *self;
+        match facing {
+            0 => Self {
+                normal: right,
+                right: neg(normal),
+                down,
+            },
+            1 => Self {
+                normal: down,
+                right,
+                down: neg(normal),
+            },
+            2 => Self {
+                normal: neg(right),
+                right: normal,
+                down,
+            },
+            _ => Self {
+                normal: neg(down),
+                right,
+                down: normal,
+            },
+        }
+    }
+
+    fn direction(&self, facing: usize) -> Vector {
+        match facing {
+            0 => self.right,
+            1 => self.down,
+            2 => neg(self.right),
+            _ => neg(self.down),
+        }
+    }
+}
+
+// The cube is centred at the origin with coordinates doubled, so every tile
+// centre has integer coordinates and the faces lie at +-size.
+struct Cube {
+    size: i64,
+    // Keyed by the face's block (row, col) in the net.
+    faces: BTreeMap<(i64, i64), Face>,
+}
+
+impl Cube {
+    fn fold(board: &Board) -> Result<Self, StringError> {
+        let n_tiles = board.tiles.iter().flatten().filter(|t| **t != b' ').count() as i64;
+        let size = (1..).find(|s| 6 * s * s >= n_tiles).unwrap();
+        if 6 * size * size != n_tiles {
+            return Err("d22: the board is not the net of a cube.".into());
+        }
+
+        let is_face = |block: (i64, i64)| board.tile(block.0 * size, block.1 * size) != b' ';
+
+        let n_block_rows = board.tiles.len() as i64 / size;
+        let n_block_cols = board.tiles.iter().map(|r| r.len()).max().unwrap_or(0) as i64 / size;
+        let first = (0..n_block_cols)
+            .map(|c| (0, c))
+            .find(|b| is_face(*b))
+            .ok_or("d22: the board is not the net of a cube.")?;
+
+        let mut faces = BTreeMap::new();
+        faces.insert(
+            first,
+            Face {
+                normal: [0, 0, 1],
+                right: [1, 0, 0],
+                down: [0, 1, 0],
+            },
+        );
+
+        let mut todo = VecDeque::new();
+        todo.push_back(first);
+        while let Some(block) = todo.pop_front() {
+            let face = faces[&block];
+            for (facing, (dr, dc)) in DIRECTIONS.iter().enumerate() {
+                let next = (block.0 + dr, block.1 + dc);
+                if next.0 < 0 || next.0 >= n_block_rows || next.1 < 0 || next.1 >= n_block_cols {
+                    continue;
+                }
+                if !is_face(next) || faces.contains_key(&next) {
+                    continue;
+                }
+                faces.insert(next, face.folded(facing));
+                todo.push_back(next);
+            }
+        }
+
+        // Every block has to be a full face, and every side of the cube needs
+        // exactly one of them.
+        let mut normals: Vec<Vector> = faces.values().map(|f| f.normal).collect();
+        normals.sort();
+        normals.dedup();
+        if faces.len() != 6 || normals.len() != 6 {
+            return Err("d22: the board is not the net of a cube.".into());
+        }
+        for block in faces.keys() {
+            for row in block.0 * size..(block.0 + 1) * size {
+                for col in block.1 * size..(block.1 + 1) * size {
+                    if board.tile(row, col) == b' ' {
+                        return Err("d22: the board is not the net of a cube.".into());
+                    }
+                }
+            }
+        }
+
+        Ok(Self { size, faces })
+    }
+
+    fn face_at(&self, row: i64, col: i64) -> ((i64, i64), Face) {
+        let block = (row.div_euclid(self.size), col.div_euclid(self.size));
+        (block, self.faces[&block])
+    }
+
+    fn position(&self, state: State) -> Vector {
+        let (block, face) = self.face_at(state.row, state.col);
+        let i = state.row - block.0 * self.size;
+        let j = state.col - block.1 * self.size;
+        let s = self.size;
+
+        let on_face = scale(face.normal, s);
+        let col_offset = scale(face.right, 2 * j + 1 - s);
+        let row_offset = scale(face.down, 2 * i + 1 - s);
+        add(add(on_face, col_offset), row_offset)
+    }
+
+    // Steps over the cube edge: the tile on the neighbouring face is one unit
+    // further in the travel direction and one unit deeper than this face.
+    fn wrap(&self, state: State) -> State {
+        let (_, face) = self.face_at(state.row, state.col);
+        let travel = face.direction(state.facing);
+        let p = add(add(self.position(state), travel), neg(face.normal));
+
+        let (block, next) = self.faces.iter().find(|(_, f)| f.normal == travel).unwrap();
+
+        let facing = (0..4)
+            .find(|f| next.direction(*f) == neg(face.normal))
+            .unwrap();
+        let j = (dot(p, next.right) + self.size - 1) / 2;
+        let i = (dot(p, next.down) + self.size - 1) / 2;
+
+        State {
+            row: block.0 * self.size + i,
+            col: block.1 * self.size + j,
+            facing,
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Board, Vec<Instruction>), StringError> {
+    let (board, path) = input
+        .split_once("\n\n")
+        .ok_or("d22: Invalid input. Expected an empty line between board and path")?;
+
+    let mut tiles = Vec::new();
+    for line in board.lines() {
+        if line.chars().any(|c| c != ' ' && c != '.' && c != '#') {
+            return Err("d22: Invalid board char.".into());
+        }
+        tiles.push(line.as_bytes().to_vec());
+    }
+    let board = Board { tiles };
+    if !board.tiles.first().is_some_and(|r| r.contains(&b'.')) {
+        return Err("d22: no open tile in the first row.".into());
+    }
+
+    let mut instructions = Vec::new();
+    let mut number = None;
+    for c in path.trim().chars() {
+        if let Some(d) = c.to_digit(10) {
+            let n = number.unwrap_or(0usize);
+            number = Some(
+                n.checked_mul(10)
+                    .and_then(|n| n.checked_add(d as usize))
+                    .ok_or("d22: number of steps too large.")?,
+            );
+            continue;
+        }
+
+        if let Some(n) = number.take() {
+            instructions.push(Instruction::Forward(n));
+        }
+        match c {
+            'L' => instructions.push(Instruction::Left),
+            'R' => instructions.push(Instruction::Right),
+            _ => return Err("d22: Invalid path char.".into()),
+        }
+    }
+    if let Some(n) = number {
+        instructions.push(Instruction::Forward(n));
+    }
+
+    Ok((board, instructions))
+}
@@ -0,0 +1,140 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::point::Point;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let input = input.trim();
+
+    let mut elves = HashSet::new();
+    for (y, line) in input.lines().enumerate() {
+        for (x, c) in line.trim().chars().enumerate() {
+            match c {
+                '#' => {
+                    elves.insert(Point::new(x as i64, y as i64));
+                }
+                '.' => (),
+                _ => return Err("d23: Invalid input char.".into()),
+            }
+        }
+    }
+    if elves.is_empty() {
+        return Err("d23: received no elves.".into());
+    }
+
+    // The elves may already stop moving within the first ten rounds.
+    let mut field = Field { elves, round: 0 };
+    let mut first_round_without_move = None;
+    for _ in 0..10 {
+        if !field.step() && first_round_without_move.is_none() {
+            first_round_without_move = Some(field.round);
+        }
+    }
+    let solution1 = field.empty_ground();
+
+    let solution2 = match first_round_without_move {
+        Some(round) => round,
+        None => {
+            while field.step() {}
+            field.round
+        }
+    };
+
+    let mut result = format!("Day 23/01: {}\n", solution1);
+    result += &format!("Day 23/02: {}\n", solution2);
+    Ok(result)
+}
+
+// y points down, so north is -y. Each direction comes with the three
+// neighbors that have to be free to move there.
+const DIRECTIONS: [(Point, [Point; 3]); 4] = [
+    (
+        Point { x: 0, y: -1 },
+        [
+            Point { x: -1, y: -1 },
+            Point { x: 0, y: -1 },
+            Point { x: 1, y: -1 },
+        ],
+    ),
+    (
+        Point { x: 0, y: 1 },
+        [
+            Point { x: -1, y: 1 },
+            Point { x: 0, y: 1 },
+            Point { x: 1, y: 1 },
+        ],
+    ),
+    (
+        Point { x: -1, y: 0 },
+        [
+            Point { x: -1, y: -1 },
+            Point { x: -1, y: 0 },
+            Point { x: -1, y: 1 },
+        ],
+    ),
+    (
+        Point { x: 1, y: 0 },
+        [
+            Point { x: 1, y: -1 },
+            Point { x: 1, y: 0 },
+            Point { x: 1, y: 1 },
+        ],
+    ),
+];
+
+// The field grows without bounds, so only the elves' positions are stored.
+struct Field {
+    elves: HashSet<Point>,
+    round: usize,
+}
+
+impl Field {
+    fn is_free(&self, p: Point) -> bool {
+        !self.elves.contains(&p)
+    }
+
+    fn proposal(&self, elf: Point) -> Option<Point> {
+        let alone = (-1..=1)
+            .flat_map(|dy| (-1..=1).map(move |dx| Point::new(dx, dy)))
+            .filter(|d| *d != Point::new(0, 0))
+            .all(|d| self.is_free(elf + d));
+        if alone {
+            return None;
+        }
+
+        (0..DIRECTIONS.len())
+            .map(|i| &DIRECTIONS[(self.round + i) % DIRECTIONS.len()])
+            .find(|(_, checked)| checked.iter().all(|d| self.is_free(elf + *d)))
+            .map(|(d, _)| elf + *d)
+    }
+
+    // Returns whether any elf moved.
+    fn step(&mut self) -> bool {
+        let mut proposals: HashMap<Point, Vec<Point>> = HashMap::new();
+        for elf in self.elves.iter() {
+            if let Some(p) = self.proposal(*elf) {
+                proposals.entry(p).or_default().push(*elf);
+            }
+        }
+        self.round += 1;
+
+        let mut moved = false;
+        for (target, elves) in proposals.into_iter() {
+            if elves.len() == 1 {
+                self.elves.remove(&elves[0]);
+                self.elves.insert(target);
+                moved = true;
+            }
+        }
+        moved
+    }
+
+    fn empty_ground(&self) -> i64 {
+        let min_x = self.elves.iter().map(|e| e.x).min().unwrap();
+        let max_x = self.elves.iter().map(|e| e.x).max().unwrap();
+        let min_y = self.elves.iter().map(|e| e.y).min().unwrap();
+        let max_y = self.elves.iter().map(|e| e.y).max().unwrap();
+
+        (max_x - min_x + 1) * (max_y - min_y + 1) - self.elves.len() as i64
+    }
+}
@@ -0,0 +1,170 @@
+use std::collections::VecDeque;
+
+use crate::grid::neighbors;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let input = input.trim();
+
+    let valley = Valley::from_input(input)?;
+
+    let there = valley.cross(valley.start, valley.end, 0);
+    let back = there.and_then(|t| valley.cross(valley.end, valley.start, t));
+    let there_again = back.and_then(|t| valley.cross(valley.start, valley.end, t));
+
+    let mut result = String::new();
+    if let Some(t) = there {
+        result += &format!("Day 24/01: {}\n", t);
+    } else {
+        result += "Day 24/01: Did not find route.\n"
+    }
+    if let Some(t) = there_again {
+        result += &format!("Day 24/02: {}\n", t);
+    } else {
+        result += "Day 24/02: Did not find route.\n"
+    }
+
+    Ok(result)
+}
+
+// The blizzards repeat after `period` minutes, so the grid is stored once for
+// every minute of a period. Indices are row by row, including the walls.
+struct Valley {
+    width: usize,
+    height: usize,
+    walls: Vec<bool>,
+    period: usize,
+    // blocked[t][index]: a blizzard is there at minute t (mod period).
+    blocked: Vec<Vec<bool>>,
+    start: usize,
+    end: usize,
+}
+
+impl Valley {
+    // Earliest minute at which `to` is reached when leaving `from` at minute
+    // `departure`. Waiting is allowed anywhere the blizzards allow it.
+    fn cross(&self, from: usize, to: usize, departure: usize) -> Option<usize> {
+        let mut visited = vec![vec![false; self.walls.len()]; self.period];
+        visited[departure % self.period][from] = true;
+
+        let mut todo = VecDeque::new();
+        todo.push_back((from, departure));
+
+        while let Some((index, time)) = todo.pop_front() {
+            if index == to {
+                return Some(time);
+            }
+
+            let next_time = time + 1;
+            let blocked = &self.blocked[next_time % self.period];
+            let visited = &mut visited[next_time % self.period];
+
+            let i = index / self.width;
+            let j = index % self.width;
+
+            let moves =
+                neighbors(i, j, self.height, self.width).map(|(ni, nj)| ni * self.width + nj);
+            for next in moves.chain(std::iter::once(index)) {
+                if self.walls[next] || blocked[next] || visited[next] {
+                    continue;
+                }
+                visited[next] = true;
+                todo.push_back((next, next_time));
+            }
+        }
+
+        None
+    }
+
+    fn from_input(input: &str) -> Result<Self, StringError> {
+        let lines: Vec<&str> = input.lines().map(|l| l.trim()).collect();
+        if lines.is_empty() {
+            return Err("d24: received no input.".into());
+        }
+        let height = lines.len();
+        let width = lines[0].chars().count();
+        if height < 3 || width < 3 {
+            return Err("d24: valley too small.".into());
+        }
+
+        let mut walls = Vec::new();
+        // (row, col, drow, dcol) of every blizzard.
+        let mut blizzards = Vec::new();
+
+        for (i, line) in lines.iter().enumerate() {
+            if line.chars().count() != width {
+                return Err("d24: input must be a rectangle.".into());
+            }
+            for (j, c) in line.chars().enumerate() {
+                let on_border = i == 0 || i == height - 1 || j == 0 || j == width - 1;
+                let direction = match c {
+                    '>' => Some((0, 1)),
+                    '<' => Some((0, -1)),
+                    '^' => Some((-1, 0)),
+                    'v' => Some((1, 0)),
+                    '#' | '.' => None,
+                    _ => return Err("d24: Invalid input char.".into()),
+                };
+
+                if on_border && c != '#' && c != '.' {
+                    return Err("d24: blizzard on the border.".into());
+                }
+                if !on_border && c == '#' {
+                    return Err("d24: wall inside the valley.".into());
+                }
+                if let Some((di, dj)) = direction {
+                    blizzards.push((i as i64, j as i64, di, dj));
+                }
+
+                walls.push(c == '#');
+            }
+        }
+
+        let find_gap = |i: usize| -> Result<usize, StringError> {
+            let gaps: Vec<usize> = (0..width).filter(|j| !walls[i * width + j]).collect();
+            if gaps.len() != 1 {
+                return Err("d24: expected exactly one gap in the top and bottom wall.".into());
+            }
+            Ok(i * width + gaps[0])
+        };
+        let start = find_gap(0)?;
+        let end = find_gap(height - 1)?;
+
+        // Blizzards move within the inner part, wrapping around at the walls.
+        let inner_height = (height - 2) as i64;
+        let inner_width = (width - 2) as i64;
+        let period = lcm(inner_height as usize, inner_width as usize);
+
+        let mut blocked = vec![vec![false; walls.len()]; period];
+        for (t, b) in blocked.iter_mut().enumerate() {
+            let t = t as i64;
+            for (i, j, di, dj) in blizzards.iter() {
+                let ni = (i - 1 + di * t).rem_euclid(inner_height) + 1;
+                let nj = (j - 1 + dj * t).rem_euclid(inner_width) + 1;
+                b[ni as usize * width + nj as usize] = true;
+            }
+        }
+
+        Ok(Self {
+            width,
+            height,
+            walls,
+            period,
+            blocked,
+            start,
+            end,
+        })
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn lcm(a: usize, b: usize) -> usize {
+    a / gcd(a, b) * b
+}
@@ -0,0 +1,187 @@
+use std::fmt;
+use std::ops::Add;
+use std::str::FromStr;
+
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let input = input.trim();
+
+    let mut sum = Snafu::default();
+    for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        sum = sum + line.parse::<Snafu>()?;
+    }
+
+    Ok(format!("Day 25/01: {}\n", sum))
+}
+
+// Balanced base five: the digits 2, 1, 0, - and = stand for 2, 1, 0, -1
+// and -2.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Snafu {
+    // Least significant digit first, without leading zeros. Zero has none.
+    digits: Vec<i8>,
+}
+
+impl Snafu {
+    fn from_digits(mut digits: Vec<i8>) -> Self {
+        while digits.last() == Some(&0) {
+            digits.pop();
+        }
+        Self { digits }
+    }
+}
+
+impl FromStr for Snafu {
+    type Err = StringError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err("d25: empty SNAFU number.".into());
+        }
+
+        let mut digits = Vec::new();
+        for c in s.chars().rev() {
+            match c {
+                '2' => digits.push(2),
+                '1' => digits.push(1),
+                '0' => digits.push(0),
+                '-' => digits.push(-1),
+                '=' => digits.push(-2),
+                _ => return Err(format!("d25: invalid SNAFU digit {}.", c).into()),
+            }
+        }
+
+        Ok(Self::from_digits(digits))
+    }
+}
+
+impl fmt::Display for Snafu {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.digits.is_empty() {
+            return write!(f, "0");
+        }
+
+        for d in self.digits.iter().rev() {
+            let c = match d {
+                2 => '2',
+                1 => '1',
+                0 => '0',
+                -1 => '-',
+                _ => '=',
+            };
+            write!(f, "{}", c)?;
+        }
+        Ok(())
+    }
+}
+
+// Adds digit by digit, carrying whenever a digit leaves -2..=2.
+impl Add for &Snafu {
+    type Output = Snafu;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        let n = self.digits.len().max(rhs.digits.len());
+        let mut digits = Vec::with_capacity(n + 1);
+        let mut carry = 0;
+
+        for i in 0..n {
+            let a = self.digits.get(i).copied().unwrap_or(0);
+            let b = rhs.digits.get(i).copied().unwrap_or(0);
+            let sum = a + b + carry;
+            let digit = (sum + 2).rem_euclid(5) - 2;
+            carry = (sum - digit) / 5;
+            digits.push(digit);
+        }
+        digits.push(carry);
+
+        Snafu::from_digits(digits)
+    }
+}
+
+impl Add for Snafu {
+    type Output = Snafu;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        &self + &rhs
+    }
+}
+
+impl From<i128> for Snafu {
+    fn from(value: i128) -> Self {
+        let mut digits = Vec::new();
+        let mut v = value;
+
+        while v != 0 {
+            // Remainders 3 and 4 become -2 and -1 with a carry of one. Never
+            // adding before dividing keeps i128::MIN and MAX from overflowing.
+            let r = v.rem_euclid(5);
+            let (digit, carry) = if r > 2 { (r - 5, 1) } else { (r, 0) };
+            digits.push(digit as i8);
+            v = v.div_euclid(5) + carry;
+        }
+
+        Self::from_digits(digits)
+    }
+}
+
+impl From<i64> for Snafu {
+    fn from(value: i64) -> Self {
+        Self::from(value as i128)
+    }
+}
+
+impl TryFrom<&Snafu> for i128 {
+    type Error = StringError;
+
+    fn try_from(value: &Snafu) -> Result<Self, Self::Error> {
+        // 5 * result can leave the range by a little even if adding the
+        // digit brings it back, as for i128::MIN. (4 * result + digit) + result
+        // stays within it whenever the final value does.
+        let mut result: i128 = 0;
+        for d in value.digits.iter().rev() {
+            result = result
+                .checked_mul(4)
+                .and_then(|r| r.checked_add(*d as i128))
+                .and_then(|r| r.checked_add(result))
+                .ok_or("d25: SNAFU number does not fit into i128.")?;
+        }
+        Ok(result)
+    }
+}
+
+impl TryFrom<&Snafu> for i64 {
+    type Error = StringError;
+
+    fn try_from(value: &Snafu) -> Result<Self, Self::Error> {
+        let v = i128::try_from(value)?;
+        i64::try_from(v).map_err(|_| "d25: SNAFU number does not fit into i64.".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn i128_round_trip() {
+        for v in [i128::MIN, i128::MIN + 1, -1, 0, 1, i128::MAX - 1, i128::MAX] {
+            assert_eq!(i128::try_from(&Snafu::from(v)).unwrap(), v);
+        }
+    }
+
+    #[test]
+    fn i64_round_trip() {
+        for v in [i64::MIN, -1, 0, 1, i64::MAX] {
+            assert_eq!(i64::try_from(&Snafu::from(v)).unwrap(), v);
+        }
+    }
+
+    #[test]
+    fn too_large_for_i128() {
+        let v = Snafu::from(i128::MAX) + Snafu::from(1i128);
+        assert!(i128::try_from(&v).is_err());
+        let v = Snafu::from(i128::MIN) + Snafu::from(-1i128);
+        assert!(i128::try_from(&v).is_err());
+    }
+}
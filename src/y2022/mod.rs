@@ -0,0 +1,178 @@
+// Solutions for the 2022 event.
+pub mod d01;
+pub mod d02;
+pub mod d03;
+pub mod d04;
+pub mod d05;
+pub mod d06;
+pub mod d07;
+pub mod d08;
+pub mod d09;
+pub mod d10;
+pub mod d11;
+pub mod d12;
+pub mod d13;
+pub mod d14;
+pub mod d15;
+pub mod d16;
+pub mod d17;
+pub mod d18;
+pub mod d19;
+pub mod d20;
+pub mod d21;
+pub mod d22;
+pub mod d23;
+pub mod d24;
+pub mod d25;
+pub mod reference;
+
+use crate::differential::Case;
+use crate::string_error::StringError;
+use crate::tool::DayTool;
+use crate::Solver;
+
+pub const YEAR: u32 = 2022;
+
+pub const SOLVERS: [Solver; 25] = [
+    d01::solve,
+    d02::solve,
+    d03::solve,
+    d04::solve,
+    d05::solve,
+    d06::solve,
+    d07::solve,
+    d08::solve,
+    d09::solve,
+    d10::solve,
+    d11::solve,
+    d12::solve,
+    d13::solve,
+    d14::solve,
+    d15::solve,
+    d16::solve,
+    d17::solve,
+    d18::solve,
+    d19::solve,
+    d20::solve,
+    d21::solve,
+    d22::solve,
+    d23::solve,
+    d24::solve,
+    d25::solve,
+];
+
+fn d15_small(input: &str) -> Result<String, StringError> {
+    d15::solve_with_bounds(input, reference::d15::ROW, reference::d15::XY_MAX)
+}
+
+pub const CASES: [Case; 15] = [
+    Case {
+        day: 1,
+        solve: d01::solve,
+        reference: reference::d01::solve,
+        generate: reference::d01::generate,
+    },
+    Case {
+        day: 2,
+        solve: d02::solve,
+        reference: reference::d02::solve,
+        generate: reference::d02::generate,
+    },
+    Case {
+        day: 3,
+        solve: d03::solve,
+        reference: reference::d03::solve,
+        generate: reference::d03::generate,
+    },
+    Case {
+        day: 4,
+        solve: d04::solve,
+        reference: reference::d04::solve,
+        generate: reference::d04::generate,
+    },
+    Case {
+        day: 5,
+        solve: d05::solve,
+        reference: reference::d05::solve,
+        generate: reference::d05::generate,
+    },
+    Case {
+        day: 6,
+        solve: d06::solve,
+        reference: reference::d06::solve,
+        generate: reference::d06::generate,
+    },
+    Case {
+        day: 7,
+        solve: d07::solve,
+        reference: reference::d07::solve,
+        generate: reference::d07::generate,
+    },
+    Case {
+        day: 8,
+        solve: d08::solve,
+        reference: reference::d08::solve,
+        generate: reference::d08::generate,
+    },
+    Case {
+        day: 9,
+        solve: d09::solve,
+        reference: reference::d09::solve,
+        generate: reference::d09::generate,
+    },
+    Case {
+        day: 10,
+        solve: d10::solve,
+        reference: reference::d10::solve,
+        generate: reference::d10::generate,
+    },
+    Case {
+        day: 11,
+        solve: d11::solve,
+        reference: reference::d11::solve,
+        generate: reference::d11::generate,
+    },
+    Case {
+        day: 12,
+        solve: d12::solve,
+        reference: reference::d12::solve,
+        generate: reference::d12::generate,
+    },
+    Case {
+        day: 13,
+        solve: d13::solve,
+        reference: reference::d13::solve,
+        generate: reference::d13::generate,
+    },
+    Case {
+        day: 14,
+        solve: d14::solve,
+        reference: reference::d14::solve,
+        generate: reference::d14::generate,
+    },
+    Case {
+        day: 15,
+        solve: d15_small,
+        reference: reference::d15::solve,
+        generate: reference::d15::generate,
+    },
+];
+
+pub const TOOLS: [DayTool; 4] = [
+    DayTool {
+        day: 1,
+        run: d01::tool,
+    },
+    DayTool {
+        day: 2,
+        run: d02::tool,
+    },
+    DayTool {
+        day: 3,
+        run: d03::tool,
+    },
+    DayTool {
+        day: 4,
+        run: d04::tool,
+    },
+];
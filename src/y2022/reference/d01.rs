@@ -0,0 +1,63 @@
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let mut totals = Vec::new();
+    let mut current = None;
+
+    for line in input.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            totals.extend(current.take());
+            continue;
+        }
+
+        let v = line
+            .parse::<u64>()
+            .map_err(|_| "reference d01: could not parse number")?;
+        current = Some(current.unwrap_or(0) + v);
+    }
+    totals.extend(current);
+
+    totals.sort_unstable_by(|a, b| b.cmp(a));
+    totals.resize(totals.len().max(3), 0);
+
+    let mut result = format!("Day 01/01: {}\n", totals[0]);
+    result += &format!("Day 01/02: {}\n", totals[..3].iter().sum::<u64>());
+    Ok(result)
+}
+
+// Besides the plain format, covers what the solver has to accept as well:
+// several blank lines between elves, any number of trailing blank lines
+// (including none), CRLF line endings and numbers padded with whitespace.
+pub fn generate(rng: &mut Rng) -> String {
+    let newline = if rng.chance(1, 4) { "\r\n" } else { "\n" };
+    let paddings = ["", "", "", " ", "  ", "\t"];
+
+    let mut result = String::new();
+    let n_elves = rng.range(1, 8);
+
+    for i in 0..n_elves {
+        if i > 0 {
+            let max_blank_lines = if rng.chance(1, 4) { 3 } else { 1 };
+            for _ in 0..rng.range(1, max_blank_lines + 1) {
+                result += newline;
+            }
+        }
+        for _ in 0..rng.range(1, 5) {
+            result += &format!(
+                "{}{}{}{}",
+                rng.pick(&paddings),
+                rng.range(1, 10_000),
+                rng.pick(&paddings),
+                newline
+            );
+        }
+    }
+
+    for _ in 0..rng.range(0, 3) {
+        result += newline;
+    }
+
+    result
+}
@@ -0,0 +1,67 @@
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let mut points_part_one = 0;
+    let mut points_part_two = 0;
+
+    for line in input.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        let symbols: Vec<&str> = line.split(' ').collect();
+        if symbols.len() < 2 {
+            return Err("reference d02: expected two symbols".into());
+        }
+
+        let other = ["A", "B", "C"]
+            .iter()
+            .position(|s| *s == symbols[0])
+            .ok_or("reference d02: invalid opponent symbol")?;
+        let second = ["X", "Y", "Z"]
+            .iter()
+            .position(|s| *s == symbols[1])
+            .ok_or("reference d02: invalid second symbol")?;
+
+        points_part_one += score(second, other);
+
+        // X loses, Y draws, Z wins; try every move and keep the one with that outcome.
+        let wanted = second as u64 * 3;
+        let own = (0..3).find(|own| outcome(*own, other) == wanted).unwrap();
+        points_part_two += score(own, other);
+    }
+
+    let mut result = format!("Day 02/01: {}\n", points_part_one);
+    result += &format!("Day 02/02: {}\n", points_part_two);
+    Ok(result)
+}
+
+// Moves are 0 = rock, 1 = paper, 2 = scissors.
+fn outcome(own: usize, other: usize) -> u64 {
+    let beats = [(0, 2), (1, 0), (2, 1)];
+    if own == other {
+        3
+    } else if beats.contains(&(own, other)) {
+        6
+    } else {
+        0
+    }
+}
+
+fn score(own: usize, other: usize) -> u64 {
+    own as u64 + 1 + outcome(own, other)
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let mut result = String::new();
+    for _ in 0..rng.range(1, 20) {
+        result += &format!(
+            "{} {}\n",
+            rng.pick(&["A", "B", "C"]),
+            rng.pick(&["X", "Y", "Z"])
+        );
+    }
+    result
+}
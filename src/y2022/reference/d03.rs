@@ -0,0 +1,70 @@
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let lines: Vec<&str> = input
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .collect();
+    let mut sum_priority = 0;
+    let mut sum_badge_priority = 0;
+
+    for line in lines.iter() {
+        let n = line.chars().count();
+        if n % 2 != 0 {
+            return Err("reference d03: odd number of items".into());
+        }
+
+        let left: Vec<char> = line.chars().take(n / 2).collect();
+        let right: Vec<char> = line.chars().skip(n / 2).collect();
+
+        for c in ITEMS.chars() {
+            if left.contains(&c) && right.contains(&c) {
+                sum_priority += priority(c)?;
+            }
+        }
+    }
+
+    if !lines.len().is_multiple_of(3) {
+        return Err("reference d03: incomplete group".into());
+    }
+    for group in lines.chunks_exact(3) {
+        for c in ITEMS.chars() {
+            if group.iter().all(|l| l.contains(c)) {
+                sum_badge_priority += priority(c)?;
+            }
+        }
+    }
+
+    let mut result = format!("Day 03/01: {}\n", sum_priority);
+    result += &format!("Day 03/02: {}\n", sum_badge_priority);
+    Ok(result)
+}
+
+fn priority(c: char) -> Result<u64, StringError> {
+    ITEMS
+        .chars()
+        .position(|d| d == c)
+        .map(|p| p as u64 + 1)
+        .ok_or_else(|| "reference d03: illegal item".into())
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let items: Vec<char> = ITEMS.chars().collect();
+    let mut result = String::new();
+
+    for _ in 0..(3 * rng.range(1, 4)) {
+        // A small alphabet per line makes shared items likely.
+        let alphabet_size = rng.range(2, 53) as usize;
+        let half = rng.range(1, 8);
+        for _ in 0..(2 * half) {
+            result.push(items[rng.index(alphabet_size)]);
+        }
+        result.push('\n');
+    }
+
+    result
+}
@@ -0,0 +1,48 @@
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let mut count_fully_contained = 0;
+    let mut count_overlapping = 0;
+
+    for line in input.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        let numbers = line
+            .split([',', '-'])
+            .map(|s| s.trim().parse::<i64>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| "reference d04: could not parse number")?;
+        if numbers.len() != 4 {
+            return Err("reference d04: expected four numbers".into());
+        }
+
+        let a: Vec<i64> = (numbers[0]..=numbers[1]).collect();
+        let b: Vec<i64> = (numbers[2]..=numbers[3]).collect();
+
+        if a.iter().all(|v| b.contains(v)) || b.iter().all(|v| a.contains(v)) {
+            count_fully_contained += 1;
+        }
+        if a.iter().any(|v| b.contains(v)) {
+            count_overlapping += 1;
+        }
+    }
+
+    let mut result = format!("Day 04/01: {}\n", count_fully_contained);
+    result += &format!("Day 04/02: {}\n", count_overlapping);
+    Ok(result)
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let mut result = String::new();
+    for _ in 0..rng.range(1, 20) {
+        let a = rng.range(1, 20);
+        let b = rng.range(a, 21);
+        let c = rng.range(1, 20);
+        let d = rng.range(c, 21);
+        result += &format!("{}-{},{}-{}\n", a, b, c, d);
+    }
+    result
+}
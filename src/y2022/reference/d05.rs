@@ -0,0 +1,117 @@
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+// The real solver always reports nine stacks, empty ones as a space.
+const N_STACKS: usize = 9;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let mut stacks: Vec<Vec<char>> = vec![Vec::new(); N_STACKS];
+    let mut lines = input.lines();
+
+    for line in lines.by_ref() {
+        if line.trim().is_empty() || line.contains('1') {
+            break;
+        }
+        for (i, c) in line.chars().skip(1).step_by(4).enumerate() {
+            if c == ' ' {
+                continue;
+            }
+            if !c.is_ascii_uppercase() || i >= N_STACKS {
+                return Err("reference d05: invalid crate".into());
+            }
+            stacks[i].insert(0, c);
+        }
+    }
+
+    let mut stacks9000 = stacks.clone();
+    let mut stacks9001 = stacks;
+
+    for line in lines.map(|l| l.trim()).filter(|l| l.starts_with("move")) {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        if words.len() != 6 {
+            return Err("reference d05: invalid move".into());
+        }
+        let parse = |s: &str| {
+            s.parse::<usize>()
+                .map_err(|_| StringError::from("reference d05: invalid move"))
+        };
+        let n = parse(words[1])?;
+        let from = parse(words[3])?;
+        let to = parse(words[5])?;
+        if from == 0 || to == 0 || from > N_STACKS || to > N_STACKS {
+            return Err("reference d05: invalid stack".into());
+        }
+        if stacks9000[from - 1].len() < n {
+            return Err("reference d05: tried to move too many crates".into());
+        }
+
+        for _ in 0..n {
+            let c = stacks9000[from - 1].pop().unwrap();
+            stacks9000[to - 1].push(c);
+        }
+
+        let split_at = stacks9001[from - 1].len() - n;
+        let moved = stacks9001[from - 1].split_off(split_at);
+        stacks9001[to - 1].extend(moved);
+    }
+
+    let topword = |stacks: &[Vec<char>]| -> String {
+        stacks
+            .iter()
+            .map(|s| s.last().copied().unwrap_or(' '))
+            .collect()
+    };
+
+    let mut result = format!("Day 05/01: {}\n", topword(&stacks9000));
+    result += &format!("Day 05/02: {}\n", topword(&stacks9001));
+    Ok(result)
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let n_stacks = rng.range(2, N_STACKS as i64 + 1) as usize;
+    let mut stacks: Vec<Vec<char>> = (0..n_stacks)
+        .map(|_| {
+            (0..rng.range(0, 5))
+                .map(|_| (b'A' + rng.range(0, 26) as u8) as char)
+                .collect()
+        })
+        .collect();
+
+    let max_height = stacks.iter().map(|s| s.len()).max().unwrap();
+    let mut result = String::new();
+    for level in (0..max_height).rev() {
+        let mut row = String::new();
+        for s in stacks.iter() {
+            match s.get(level) {
+                Some(c) => row += &format!("[{}] ", c),
+                None => row += "    ",
+            }
+        }
+        result += row.trim_end();
+        result.push('\n');
+    }
+    for i in 0..n_stacks {
+        result += &format!(" {}  ", i + 1);
+    }
+    result += "\n\n";
+
+    for _ in 0..rng.range(0, 10) {
+        let non_empty: Vec<usize> = (0..n_stacks).filter(|i| !stacks[*i].is_empty()).collect();
+        if non_empty.is_empty() {
+            break;
+        }
+        let from = *rng.pick(&non_empty);
+        let mut to = rng.index(n_stacks - 1);
+        if to >= from {
+            to += 1;
+        }
+        let n = rng.range(1, stacks[from].len() as i64 + 1) as usize;
+        let split_at = stacks[from].len() - n;
+        let moved = stacks[from].split_off(split_at);
+        stacks[to].extend(moved);
+
+        result += &format!("move {} from {} to {}\n", n, from + 1, to + 1);
+    }
+
+    result
+}
@@ -0,0 +1,42 @@
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let chars: Vec<char> = input.trim().chars().collect();
+    if chars.iter().any(|c| !c.is_ascii_lowercase()) {
+        return Err("reference d06: invalid input".into());
+    }
+
+    let marker = |n: usize| -> Result<usize, StringError> {
+        (n..=chars.len())
+            .find(|end| {
+                let window = &chars[end - n..*end];
+                window
+                    .iter()
+                    .enumerate()
+                    .all(|(i, c)| !window[i + 1..].contains(c))
+            })
+            .ok_or_else(|| "reference d06: no marker found".into())
+    };
+
+    let mut result = format!("Day 06/01: {}\n", marker(4)?);
+    result += &format!("Day 06/02: {}\n", marker(14)?);
+    Ok(result)
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let alphabet_size = rng.range(14, 27);
+    let mut result: String = (0..rng.range(20, 120))
+        .map(|_| (b'a' + rng.range(0, alphabet_size) as u8) as char)
+        .collect();
+
+    // Make sure a start-of-message marker exists.
+    let mut letters: Vec<char> = ('a'..='z').collect();
+    for _ in 0..14 {
+        let c = letters.remove(rng.index(letters.len()));
+        result.push(c);
+    }
+    result.push('\n');
+
+    result
+}
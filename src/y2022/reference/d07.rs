@@ -0,0 +1,149 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let mut cwd: Vec<String> = Vec::new();
+    let mut dirs: BTreeMap<Vec<String>, usize> = BTreeMap::new();
+    let mut files: BTreeMap<Vec<String>, usize> = BTreeMap::new();
+    dirs.insert(Vec::new(), 0);
+
+    for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        if line == "$ cd /" {
+            cwd.clear();
+        } else if line == "$ cd .." {
+            cwd.pop().ok_or("reference d07: cannot move up from root")?;
+        } else if let Some(name) = line.strip_prefix("$ cd ") {
+            cwd.push(name.to_owned());
+            if !dirs.contains_key(&cwd) {
+                return Err("reference d07: unknown directory".into());
+            }
+        } else if line == "$ ls" {
+            continue;
+        } else if let Some(name) = line.strip_prefix("dir ") {
+            let mut path = cwd.clone();
+            path.push(name.to_owned());
+            dirs.insert(path, 0);
+        } else {
+            let (size, name) = line
+                .split_once(' ')
+                .ok_or("reference d07: invalid listing")?;
+            let size = size
+                .parse::<usize>()
+                .map_err(|_| "reference d07: invalid size")?;
+            let mut path = cwd.clone();
+            path.push(name.to_owned());
+            files.insert(path, size);
+        }
+    }
+
+    for (path, size) in files.iter() {
+        for (dir, dir_size) in dirs.iter_mut() {
+            if path.starts_with(dir) {
+                *dir_size += size;
+            }
+        }
+    }
+
+    let s1: usize = dirs.values().filter(|s| **s <= 100000).sum();
+
+    let used_space = dirs[&Vec::new()];
+    if used_space > 70000000 || used_space <= 40000000 {
+        return Err("reference d07: used space out of range".into());
+    }
+    let min_space_to_free = used_space - 40000000;
+    let s2 = dirs
+        .values()
+        .filter(|s| **s >= min_space_to_free)
+        .min()
+        .unwrap();
+
+    let mut result = format!("Day 07/01: {}\n", s1);
+    result += &format!("Day 07/02: {}\n", s2);
+    Ok(result)
+}
+
+struct Dir {
+    files: Vec<(String, usize)>,
+    dirs: Vec<(String, Dir)>,
+}
+
+impl Dir {
+    fn generate(rng: &mut Rng, depth: usize, budget: &mut usize) -> Self {
+        let mut names = BTreeSet::new();
+        let mut name = |rng: &mut Rng| loop {
+            let n: String = (0..rng.range(1, 4))
+                .map(|_| (b'a' + rng.range(0, 4) as u8) as char)
+                .collect();
+            if names.insert(n.clone()) {
+                return n;
+            }
+        };
+
+        let mut files = Vec::new();
+        for _ in 0..rng.range(0, 4) {
+            let size = if rng.chance(1, 3) {
+                rng.range(1, 20_000_000) as usize
+            } else {
+                rng.range(1, 200_000) as usize
+            };
+            files.push((name(rng), size));
+        }
+
+        let mut dirs = Vec::new();
+        if depth < 4 {
+            for _ in 0..rng.range(0, 4) {
+                if *budget == 0 {
+                    break;
+                }
+                *budget -= 1;
+                let n = name(rng);
+                dirs.push((n, Dir::generate(rng, depth + 1, budget)));
+            }
+        }
+
+        Self { files, dirs }
+    }
+
+    fn size(&self) -> usize {
+        self.files.iter().map(|f| f.1).sum::<usize>()
+            + self.dirs.iter().map(|d| d.1.size()).sum::<usize>()
+    }
+
+    fn emit(&self, result: &mut String) {
+        *result += "$ ls\n";
+        for (n, _) in self.dirs.iter() {
+            *result += &format!("dir {}\n", n);
+        }
+        for (n, s) in self.files.iter() {
+            *result += &format!("{} {}\n", s, n);
+        }
+        for (n, d) in self.dirs.iter() {
+            *result += &format!("$ cd {}\n", n);
+            d.emit(result);
+            *result += "$ cd ..\n";
+        }
+    }
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    // Part two needs between 40000000 and 70000000 used.
+    let target = rng.range(40_000_001, 70_000_001) as usize;
+    let mut root = loop {
+        let mut budget = 12;
+        let root = Dir::generate(rng, 0, &mut budget);
+        if root.size() <= target {
+            break root;
+        }
+    };
+
+    let size = root.size();
+    if size < target {
+        root.files.push(("zzzz".to_owned(), target - size));
+    }
+
+    let mut result = "$ cd /\n".to_owned();
+    root.emit(&mut result);
+    result
+}
@@ -0,0 +1,72 @@
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let grid = input
+        .trim()
+        .lines()
+        .map(|l| {
+            l.trim()
+                .chars()
+                .map(|c| c.to_digit(10).ok_or("reference d08: invalid char"))
+                .collect::<Result<Vec<u32>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let height = grid.len();
+    let width = grid[0].len();
+    if grid.iter().any(|r| r.len() != width) {
+        return Err("reference d08: rows differ in width".into());
+    }
+
+    let mut visible = 0;
+    let mut best_score = 0;
+
+    for i in 0..height {
+        for j in 0..width {
+            let h = grid[i][j];
+            let lines_of_sight: [Vec<u32>; 4] = [
+                (0..j).rev().map(|k| grid[i][k]).collect(),
+                (j + 1..width).map(|k| grid[i][k]).collect(),
+                (0..i).rev().map(|k| grid[k][j]).collect(),
+                (i + 1..height).map(|k| grid[k][j]).collect(),
+            ];
+
+            if lines_of_sight.iter().any(|l| l.iter().all(|t| *t < h)) {
+                visible += 1;
+            }
+
+            let mut score = 1;
+            for l in lines_of_sight.iter() {
+                let mut seen = 0;
+                for t in l.iter() {
+                    seen += 1;
+                    if *t >= h {
+                        break;
+                    }
+                }
+                score *= seen;
+            }
+            best_score = best_score.max(score);
+        }
+    }
+
+    let mut result = format!("Day 08/01: {}\n", visible);
+    result += &format!("Day 08/02: {}\n", best_score);
+    Ok(result)
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let width = rng.range(1, 9);
+    let height = rng.range(1, 9);
+    let max_height = rng.range(1, 11);
+
+    let mut result = String::new();
+    for _ in 0..height {
+        for _ in 0..width {
+            result.push((b'0' + rng.range(0, max_height) as u8) as char);
+        }
+        result.push('\n');
+    }
+    result
+}
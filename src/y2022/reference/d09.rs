@@ -0,0 +1,59 @@
+use std::collections::BTreeSet;
+
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let mut knots = [(0i64, 0i64); 10];
+    let mut visited_part1 = BTreeSet::from([knots[1]]);
+    let mut visited_part2 = BTreeSet::from([knots[9]]);
+
+    for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        let (direction, amount) = line.split_once(' ').ok_or("reference d09: invalid line")?;
+        let amount = amount
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| "reference d09: invalid amount")?;
+        let (dx, dy) = match direction {
+            "R" => (1, 0),
+            "L" => (-1, 0),
+            "U" => (0, 1),
+            "D" => (0, -1),
+            _ => return Err("reference d09: invalid direction".into()),
+        };
+        let (dx, dy, amount) = if amount < 0 {
+            (-dx, -dy, -amount)
+        } else {
+            (dx, dy, amount)
+        };
+
+        for _ in 0..amount {
+            knots[0].0 += dx;
+            knots[0].1 += dy;
+
+            for i in 1..knots.len() {
+                let ddx = knots[i - 1].0 - knots[i].0;
+                let ddy = knots[i - 1].1 - knots[i].1;
+                if ddx.abs() > 1 || ddy.abs() > 1 {
+                    knots[i].0 += ddx.signum();
+                    knots[i].1 += ddy.signum();
+                }
+            }
+
+            visited_part1.insert(knots[1]);
+            visited_part2.insert(knots[9]);
+        }
+    }
+
+    let mut result = format!("Day 09/01: {}\n", visited_part1.len());
+    result += &format!("Day 09/02: {}\n", visited_part2.len());
+    Ok(result)
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let mut result = String::new();
+    for _ in 0..rng.range(1, 30) {
+        result += &format!("{} {}\n", rng.pick(&["R", "L", "U", "D"]), rng.range(1, 8));
+    }
+    result
+}
@@ -0,0 +1,62 @@
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    // Value of the register during each cycle, starting with cycle 1.
+    let mut during = Vec::new();
+    let mut x: i64 = 1;
+
+    for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        if line == "noop" {
+            during.push(x);
+        } else if let Some(v) = line.strip_prefix("addx ") {
+            let v = v
+                .trim()
+                .parse::<i64>()
+                .map_err(|_| "reference d10: invalid addx")?;
+            during.push(x);
+            during.push(x);
+            x += v;
+        } else {
+            return Err("reference d10: invalid instruction".into());
+        }
+    }
+
+    let signal_strength: i64 = [20, 60, 100, 140, 180, 220]
+        .iter()
+        .filter(|c| **c <= during.len())
+        .map(|c| *c as i64 * during[c - 1])
+        .sum();
+
+    let mut screen = String::new();
+    for row in 0..6 {
+        for col in 0..40 {
+            let lit = match during.get(row * 40 + col) {
+                Some(x) => (x - col as i64).abs() <= 1,
+                None => false,
+            };
+            screen += if lit { "■" } else { " " };
+        }
+        screen.push('\n');
+    }
+
+    let mut result = format!("Day 10/01: {}\n", signal_strength);
+    result += &format!("Day 10/02:\n{}", screen);
+    Ok(result)
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    // Like the real input, the program runs for the 240 cycles of one frame.
+    let mut result = String::new();
+    let mut cycles = 0;
+    while cycles < 240 {
+        if cycles < 239 && rng.chance(2, 3) {
+            result += &format!("addx {}\n", rng.range(-10, 11));
+            cycles += 2;
+        } else {
+            result += "noop\n";
+            cycles += 1;
+        }
+    }
+    result
+}
@@ -0,0 +1,175 @@
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+struct Monkey {
+    items: Vec<i64>,
+    operator: char,
+    operand: Option<i64>,
+    divisor: i64,
+    destinations: [usize; 2],
+}
+
+impl Monkey {
+    fn apply(&self, v: i64) -> Result<i64, StringError> {
+        let operand = self.operand.unwrap_or(v);
+        match self.operator {
+            '+' => v.checked_add(operand),
+            _ => v.checked_mul(operand),
+        }
+        .ok_or_else(|| "reference d11: worry level overflow".into())
+    }
+}
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let monkeys = parse(input)?;
+
+    // Part one: plain simulation.
+    let mut items: Vec<Vec<i64>> = monkeys.iter().map(|m| m.items.clone()).collect();
+    let mut inspections = vec![0; monkeys.len()];
+    for _ in 0..20 {
+        for (i, m) in monkeys.iter().enumerate() {
+            for v in std::mem::take(&mut items[i]) {
+                inspections[i] += 1;
+                let v = m.apply(v)? / 3;
+                let destination = m.destinations[usize::from(v % m.divisor != 0)];
+                items[destination].push(v);
+            }
+        }
+    }
+    let part_one = monkey_business(&inspections);
+
+    // Part two: every item keeps its remainder with respect to each divisor.
+    let divisors: Vec<i64> = monkeys.iter().map(|m| m.divisor).collect();
+    let mut items: Vec<Vec<Vec<i64>>> = monkeys
+        .iter()
+        .map(|m| {
+            m.items
+                .iter()
+                .map(|v| divisors.iter().map(|d| v % d).collect())
+                .collect()
+        })
+        .collect();
+    let mut inspections = vec![0; monkeys.len()];
+    for _ in 0..10000 {
+        for (i, m) in monkeys.iter().enumerate() {
+            for mut remainders in std::mem::take(&mut items[i]) {
+                inspections[i] += 1;
+                for (r, d) in remainders.iter_mut().zip(divisors.iter()) {
+                    *r = m.apply(*r)? % d;
+                }
+                let destination = m.destinations[usize::from(remainders[i] != 0)];
+                items[destination].push(remainders);
+            }
+        }
+    }
+    let part_two = monkey_business(&inspections);
+
+    let mut result = format!("Day 11/01: {}\n", part_one);
+    result += &format!("Day 11/02: {}\n", part_two);
+    Ok(result)
+}
+
+fn monkey_business(inspections: &[usize]) -> usize {
+    let mut sorted = inspections.to_vec();
+    sorted.sort_unstable_by(|a, b| b.cmp(a));
+    sorted.iter().take(2).product()
+}
+
+fn parse(input: &str) -> Result<Vec<Monkey>, StringError> {
+    let lines: Vec<&str> = input
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .collect();
+    let err = || StringError::from("reference d11: invalid monkey");
+
+    let number_after = |line: &str, prefix: &str| -> Result<i64, StringError> {
+        line.strip_prefix(prefix)
+            .ok_or_else(err)?
+            .trim()
+            .parse::<i64>()
+            .map_err(|_| err())
+    };
+
+    let mut result = Vec::new();
+    for chunk in lines.chunks(6) {
+        if chunk.len() != 6 || chunk[0] != format!("Monkey {}:", result.len()) {
+            return Err(err());
+        }
+
+        let items = chunk[1]
+            .strip_prefix("Starting items: ")
+            .ok_or_else(err)?
+            .split(", ")
+            .map(|s| s.parse::<i64>().map_err(|_| err()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let operation: Vec<&str> = chunk[2]
+            .strip_prefix("Operation: new = old ")
+            .ok_or_else(err)?
+            .split_whitespace()
+            .collect();
+        if operation.len() != 2 || (operation[0] != "+" && operation[0] != "*") {
+            return Err(err());
+        }
+        let operand = match operation[1] {
+            "old" => None,
+            s => Some(s.parse::<i64>().map_err(|_| err())?),
+        };
+
+        result.push(Monkey {
+            items,
+            operator: operation[0].chars().next().unwrap(),
+            operand,
+            divisor: number_after(chunk[3], "Test: divisible by ")?,
+            destinations: [
+                number_after(chunk[4], "If true: throw to monkey ")? as usize,
+                number_after(chunk[5], "If false: throw to monkey ")? as usize,
+            ],
+        });
+    }
+
+    if result
+        .iter()
+        .any(|m| m.destinations.iter().any(|d| *d >= lines.len() / 6))
+    {
+        return Err(err());
+    }
+
+    Ok(result)
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let mut primes = vec![2, 3, 5, 7, 11, 13, 17, 19, 23];
+    let n_monkeys = rng.range(2, 9) as usize;
+    let mut result = String::new();
+
+    for i in 0..n_monkeys {
+        let items: Vec<String> = (0..rng.range(1, 5))
+            .map(|_| rng.range(1, 100).to_string())
+            .collect();
+        let operation = match rng.range(0, 3) {
+            0 => "old * old".to_owned(),
+            1 => format!("old * {}", rng.range(2, 20)),
+            _ => format!("old + {}", rng.range(1, 10)),
+        };
+        let divisor = primes.remove(rng.index(primes.len()));
+        let mut other = || {
+            let j = rng.index(n_monkeys - 1);
+            if j >= i {
+                j + 1
+            } else {
+                j
+            }
+        };
+
+        result += &format!("Monkey {}:\n", i);
+        result += &format!("  Starting items: {}\n", items.join(", "));
+        result += &format!("  Operation: new = {}\n", operation);
+        result += &format!("  Test: divisible by {}\n", divisor);
+        result += &format!("    If true: throw to monkey {}\n", other());
+        result += &format!("    If false: throw to monkey {}\n\n", other());
+    }
+
+    result
+}
@@ -0,0 +1,128 @@
+use std::collections::VecDeque;
+
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let rows: Vec<Vec<char>> = input
+        .trim()
+        .lines()
+        .map(|l| l.trim().chars().collect())
+        .collect();
+    let width = rows[0].len();
+    if width == 0 || rows.iter().any(|r| r.len() != width) {
+        return Err("reference d12: not a rectangle".into());
+    }
+
+    let mut start = None;
+    let mut end = None;
+    let mut heights = Vec::new();
+    for (i, row) in rows.iter().enumerate() {
+        let mut line = Vec::new();
+        for (j, c) in row.iter().enumerate() {
+            let h = match c {
+                'S' => {
+                    start = Some((i, j));
+                    0
+                }
+                'E' => {
+                    end = Some((i, j));
+                    25
+                }
+                'a'..='z' => *c as u8 - b'a',
+                _ => return Err("reference d12: invalid char".into()),
+            };
+            line.push(h);
+        }
+        heights.push(line);
+    }
+    let start = start.ok_or("reference d12: no start")?;
+    let end = end.ok_or("reference d12: no end")?;
+
+    let part_one = shortest(&heights, start, end);
+    let mut part_two: Option<usize> = None;
+    for (i, row) in heights.iter().enumerate() {
+        for (j, h) in row.iter().enumerate() {
+            if *h == 0 {
+                if let Some(s) = shortest(&heights, (i, j), end) {
+                    part_two = Some(part_two.map_or(s, |p| p.min(s)));
+                }
+            }
+        }
+    }
+
+    let format_route = |part: usize, steps: Option<usize>| match steps {
+        Some(s) => format!("Day 12/0{}: {}\n", part, s),
+        None => format!("Day 12/0{}: Did not find route.\n", part),
+    };
+
+    let mut result = format_route(1, part_one);
+    result += &format_route(2, part_two);
+    Ok(result)
+}
+
+fn shortest(heights: &[Vec<u8>], start: (usize, usize), end: (usize, usize)) -> Option<usize> {
+    let height = heights.len();
+    let width = heights[0].len();
+    let mut distances = vec![vec![None; width]; height];
+    distances[start.0][start.1] = Some(0);
+    let mut todo = VecDeque::from([start]);
+
+    while let Some((i, j)) = todo.pop_front() {
+        let d = distances[i][j].unwrap();
+        if (i, j) == end {
+            return Some(d);
+        }
+
+        let candidates = [
+            (i.wrapping_sub(1), j),
+            (i + 1, j),
+            (i, j.wrapping_sub(1)),
+            (i, j + 1),
+        ];
+        for (ni, nj) in candidates {
+            if ni >= height || nj >= width || distances[ni][nj].is_some() {
+                continue;
+            }
+            if heights[ni][nj] > heights[i][j] + 1 {
+                continue;
+            }
+            distances[ni][nj] = Some(d + 1);
+            todo.push_back((ni, nj));
+        }
+    }
+
+    None
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let width = rng.range(2, 9);
+    let height = rng.range(1, 9);
+    let end = (rng.range(0, height), rng.range(0, width));
+    let start = loop {
+        let s = (rng.range(0, height), rng.range(0, width));
+        if s != end {
+            break s;
+        }
+    };
+
+    // Heights fall off with the distance from the end so that routes usually exist.
+    let slope = rng.range(1, 6);
+    let mut result = String::new();
+    for i in 0..height {
+        for j in 0..width {
+            let c = if (i, j) == start {
+                'S'
+            } else if (i, j) == end {
+                'E'
+            } else {
+                let distance = (i - end.0).abs() + (j - end.1).abs();
+                let h = (25 - slope * distance + rng.range(-2, 3)).clamp(0, 25);
+                (b'a' + h as u8) as char
+            };
+            result.push(c);
+        }
+        result.push('\n');
+    }
+    result
+}
@@ -0,0 +1,129 @@
+use std::cmp::Ordering;
+
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+enum Value {
+    Integer(i64),
+    List(Vec<Value>),
+}
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let packets = input
+        .lines()
+        .map(|l| l.trim())
+        .filter(|l| !l.is_empty())
+        .map(parse)
+        .collect::<Result<Vec<_>, _>>()?;
+    if packets.len() % 2 != 0 {
+        return Err("reference d13: packets must come in pairs".into());
+    }
+
+    let in_right_order: usize = packets
+        .chunks(2)
+        .enumerate()
+        .filter(|(_, pair)| compare(&pair[0], &pair[1]) == Ordering::Less)
+        .map(|(i, _)| i + 1)
+        .sum();
+
+    // Instead of sorting, count how many packets come before each divider.
+    let divider2 = parse("[[2]]")?;
+    let divider6 = parse("[[6]]")?;
+    if packets
+        .iter()
+        .any(|p| compare(p, &divider2).is_eq() || compare(p, &divider6).is_eq())
+    {
+        return Err("reference d13: packet equal to a divider packet".into());
+    }
+    let before = |divider: &Value| {
+        packets
+            .iter()
+            .filter(|p| compare(p, divider) == Ordering::Less)
+            .count()
+    };
+    let index2 = before(&divider2) + 1;
+    let index6 = before(&divider6) + 2;
+
+    let mut result = format!("Day 13/01: {}\n", in_right_order);
+    result += &format!("Day 13/02: {}\n", index2 * index6);
+    Ok(result)
+}
+
+fn compare(a: &Value, b: &Value) -> Ordering {
+    match (a, b) {
+        (Value::Integer(x), Value::Integer(y)) => x.cmp(y),
+        (Value::Integer(x), Value::List(_)) => compare(&Value::List(vec![Value::Integer(*x)]), b),
+        (Value::List(_), Value::Integer(y)) => compare(a, &Value::List(vec![Value::Integer(*y)])),
+        (Value::List(x), Value::List(y)) => {
+            for i in 0.. {
+                match (x.get(i), y.get(i)) {
+                    (None, None) => return Ordering::Equal,
+                    (None, Some(_)) => return Ordering::Less,
+                    (Some(_), None) => return Ordering::Greater,
+                    (Some(v), Some(w)) => {
+                        let c = compare(v, w);
+                        if c != Ordering::Equal {
+                            return c;
+                        }
+                    }
+                }
+            }
+            unreachable!()
+        }
+    }
+}
+
+fn parse(s: &str) -> Result<Value, StringError> {
+    let (value, rest) = parse_value(s)?;
+    if !rest.is_empty() {
+        return Err("reference d13: trailing characters".into());
+    }
+    Ok(value)
+}
+
+fn parse_value(s: &str) -> Result<(Value, &str), StringError> {
+    if let Some(mut rest) = s.strip_prefix('[') {
+        let mut items = Vec::new();
+        if let Some(r) = rest.strip_prefix(']') {
+            return Ok((Value::List(items), r));
+        }
+        loop {
+            let (item, r) = parse_value(rest)?;
+            items.push(item);
+            if let Some(r) = r.strip_prefix(',') {
+                rest = r;
+            } else if let Some(r) = r.strip_prefix(']') {
+                return Ok((Value::List(items), r));
+            } else {
+                return Err("reference d13: expected ',' or ']'".into());
+            }
+        }
+    }
+
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let v = s[..end]
+        .parse::<i64>()
+        .map_err(|_| "reference d13: invalid integer")?;
+    Ok((Value::Integer(v), &s[end..]))
+}
+
+fn generate_value(rng: &mut Rng, depth: usize) -> String {
+    if depth > 0 && rng.chance(1, 2) {
+        return rng.range(0, 11).to_string();
+    }
+    let items: Vec<String> = (0..rng.range(0, 4))
+        .map(|_| generate_value(rng, depth + 1))
+        .collect();
+    format!("[{}]", items.join(","))
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let mut result = String::new();
+    for _ in 0..rng.range(1, 6) {
+        result += &generate_value(rng, 0);
+        result.push('\n');
+        result += &generate_value(rng, 0);
+        result += "\n\n";
+    }
+    result
+}
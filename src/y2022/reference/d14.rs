@@ -0,0 +1,105 @@
+use std::collections::BTreeSet;
+
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    let mut rocks = BTreeSet::new();
+
+    for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        let points = line
+            .split(" -> ")
+            .map(|p| {
+                let (x, y) = p.split_once(',')?;
+                Some((x.trim().parse::<i64>().ok()?, y.trim().parse::<i64>().ok()?))
+            })
+            .collect::<Option<Vec<_>>>()
+            .ok_or("reference d14: invalid point")?;
+
+        for w in points.windows(2) {
+            let (a, b) = (w[0], w[1]);
+            if a.0 != b.0 && a.1 != b.1 {
+                return Err("reference d14: diagonal rock line".into());
+            }
+            for x in a.0.min(b.0)..=a.0.max(b.0) {
+                for y in a.1.min(b.1)..=a.1.max(b.1) {
+                    rocks.insert((x, y));
+                }
+            }
+        }
+    }
+
+    let max_y = rocks
+        .iter()
+        .map(|r| r.1)
+        .max()
+        .ok_or("reference d14: no rocks")?;
+    if rocks.contains(&(500, 0)) {
+        return Err("reference d14: source is blocked".into());
+    }
+
+    // Part one: count grains until one falls past the lowest rock.
+    let mut filled = rocks.clone();
+    let mut part_one = 0;
+    while let Some(p) = drop_grain(&filled, None, max_y) {
+        if filled.contains(&p) {
+            return Err("reference d14: source blocked before sand falls into the abyss".into());
+        }
+        filled.insert(p);
+        part_one += 1;
+    }
+
+    // Part two: a floor two below the lowest rock, count until the source is blocked.
+    let mut filled = rocks;
+    let mut part_two = 0;
+    while !filled.contains(&(500, 0)) {
+        let p = drop_grain(&filled, Some(max_y + 2), max_y).unwrap();
+        filled.insert(p);
+        part_two += 1;
+    }
+
+    let mut result = format!("Day 14/01: {}\n", part_one);
+    result += &format!("Day 14/02: {}\n", part_two);
+    Ok(result)
+}
+
+fn drop_grain(filled: &BTreeSet<(i64, i64)>, floor: Option<i64>, max_y: i64) -> Option<(i64, i64)> {
+    let (mut x, mut y) = (500, 0);
+    loop {
+        if floor.is_none() && y > max_y {
+            return None;
+        }
+        let blocked = |p: &(i64, i64)| filled.contains(p) || Some(p.1) == floor;
+
+        if !blocked(&(x, y + 1)) {
+            y += 1;
+        } else if !blocked(&(x - 1, y + 1)) {
+            x -= 1;
+            y += 1;
+        } else if !blocked(&(x + 1, y + 1)) {
+            x += 1;
+            y += 1;
+        } else {
+            return Some((x, y));
+        }
+    }
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    let mut result = String::new();
+    for _ in 0..rng.range(1, 6) {
+        let mut p = (rng.range(490, 511), rng.range(2, 13));
+        let mut points = vec![format!("{},{}", p.0, p.1)];
+        for k in 0..rng.range(1, 4) {
+            if k % 2 == 0 {
+                p.0 = rng.range(490, 511);
+            } else {
+                p.1 = rng.range(2, 13);
+            }
+            points.push(format!("{},{}", p.0, p.1));
+        }
+        result += &points.join(" -> ");
+        result.push('\n');
+    }
+    result
+}
@@ -0,0 +1,116 @@
+use crate::rng::Rng;
+use crate::string_error::StringError;
+
+// The real puzzle uses row 2000000 and a 4000000 square; generated inputs are
+// small enough to scan every lattice point.
+pub const ROW: i64 = 10;
+pub const XY_MAX: i64 = 20;
+
+struct Sensor {
+    position: (i64, i64),
+    beacon: (i64, i64),
+    radius: i64,
+}
+
+fn distance(a: (i64, i64), b: (i64, i64)) -> i64 {
+    (a.0 - b.0).abs() + (a.1 - b.1).abs()
+}
+
+pub fn solve_with_bounds(input: &str, row: i64, xy_max: i64) -> Result<String, StringError> {
+    let mut sensors = Vec::new();
+    for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        let err = || StringError::from("reference d15: invalid line");
+        let number = |s: &str| s.parse::<i64>().map_err(|_| err());
+
+        let rest = line.strip_prefix("Sensor at x=").ok_or_else(err)?;
+        let (sx, rest) = rest.split_once(", y=").ok_or_else(err)?;
+        let (sy, rest) = rest
+            .split_once(": closest beacon is at x=")
+            .ok_or_else(err)?;
+        let (bx, by) = rest.split_once(", y=").ok_or_else(err)?;
+
+        let position = (number(sx)?, number(sy)?);
+        let beacon = (number(bx)?, number(by)?);
+        sensors.push(Sensor {
+            position,
+            beacon,
+            radius: distance(position, beacon),
+        });
+    }
+
+    let covered = |p: (i64, i64)| sensors.iter().any(|s| distance(s.position, p) <= s.radius);
+
+    let x_min = sensors
+        .iter()
+        .map(|s| s.position.0 - s.radius)
+        .min()
+        .unwrap_or(0);
+    let x_max = sensors
+        .iter()
+        .map(|s| s.position.0 + s.radius)
+        .max()
+        .unwrap_or(0);
+    let solution1 = (x_min..=x_max)
+        .filter(|x| covered((*x, row)) && !sensors.iter().any(|s| s.beacon == (*x, row)))
+        .count();
+
+    // The distress beacon must be the only uncovered point of the search area.
+    let uncovered: Vec<(i64, i64)> = (0..=xy_max)
+        .flat_map(|y| (0..=xy_max).map(move |x| (x, y)))
+        .filter(|p| !covered(*p))
+        .collect();
+    if uncovered.len() != 1 {
+        return Err("reference d15: distress beacon position is not unique".into());
+    }
+    let solution2 = uncovered[0].0 * 4_000_000 + uncovered[0].1;
+
+    let mut result = format!("Day 15/01: {}\n", solution1);
+    result += &format!("Day 15/02: {}\n", solution2);
+    Ok(result)
+}
+
+pub fn solve(input: &str) -> Result<String, StringError> {
+    solve_with_bounds(input, ROW, XY_MAX)
+}
+
+pub fn generate(rng: &mut Rng) -> String {
+    // Hide the distress beacon first, then add sensors until every other point
+    // of the search area is covered, none of them reaching the hidden one.
+    let hidden = (rng.range(0, XY_MAX + 1), rng.range(0, XY_MAX + 1));
+    let mut sensors: Vec<Sensor> = Vec::new();
+
+    loop {
+        let uncovered: Vec<(i64, i64)> = (0..=XY_MAX)
+            .flat_map(|y| (0..=XY_MAX).map(move |x| (x, y)))
+            .filter(|p| *p != hidden)
+            .filter(|p| sensors.iter().all(|s| distance(s.position, *p) > s.radius))
+            .collect();
+        if uncovered.is_empty() {
+            break;
+        }
+
+        let position = *rng.pick(&uncovered);
+        let max_radius = distance(position, hidden) - 1;
+        let radius = if rng.chance(3, 4) {
+            max_radius
+        } else {
+            rng.range(0, max_radius + 1)
+        };
+        let dx = rng.range(-radius, radius + 1);
+        let dy = (radius - dx.abs()) * if rng.chance(1, 2) { 1 } else { -1 };
+        sensors.push(Sensor {
+            position,
+            beacon: (position.0 + dx, position.1 + dy),
+            radius,
+        });
+    }
+
+    let mut result = String::new();
+    for s in sensors.iter() {
+        result += &format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+            s.position.0, s.position.1, s.beacon.0, s.beacon.1
+        );
+    }
+    result
+}
@@ -0,0 +1,18 @@
+// Naive reference solvers used to cross-check the real solutions. Each module
+// has a `solve` producing exactly the same output format as its counterpart
+// and a `generate` producing small random inputs that follow the puzzle rules.
+pub mod d01;
+pub mod d02;
+pub mod d03;
+pub mod d04;
+pub mod d05;
+pub mod d06;
+pub mod d07;
+pub mod d08;
+pub mod d09;
+pub mod d10;
+pub mod d11;
+pub mod d12;
+pub mod d13;
+pub mod d14;
+pub mod d15;